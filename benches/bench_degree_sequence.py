@@ -0,0 +1,47 @@
+"""Benchmark of degree_sequence with and without the `rayon` feature.
+
+Build the extension once per configuration and run this script after each build:
+
+    maturin develop --release
+    python benches/bench_degree_sequence.py
+
+    maturin develop --release --features rayon
+    python benches/bench_degree_sequence.py
+
+The hypergraph is generated with a fixed seed, so both runs time the same input.
+The parallel path only pays off with more than one core; set RAYON_NUM_THREADS
+to compare thread counts.
+"""
+import argparse
+import os
+import time
+
+import rusthypergraph as hx
+
+
+def main():
+    parser = argparse.ArgumentParser(description = __doc__.splitlines()[0])
+    parser.add_argument("--nodes", type = int, default = 200_000)
+    parser.add_argument("--edges", type = int, default = 400_000)
+    parser.add_argument("--repeat", type = int, default = 5)
+    parser.add_argument("--seed", type = int, default = 42)
+    args = parser.parse_args()
+
+    # Archi di taglia 2, 3 e 4 in parti uguali
+    per_size = args.edges // 3
+    num_edges_by_size = {2: args.edges - 2 * per_size, 3: per_size, 4: per_size}
+    hypergraph = hx.random_hypergraph(args.nodes, num_edges_by_size, seed = args.seed)
+
+    timings = []
+    for _ in range(args.repeat):
+        start = time.perf_counter()
+        hx.degree_sequence(hypergraph)
+        timings.append(time.perf_counter() - start)
+
+    print(f"nodes={args.nodes} edges={args.edges} cpus={os.cpu_count()} "
+          f"threads={os.environ.get('RAYON_NUM_THREADS', 'default')}")
+    print(f"degree_sequence: best {min(timings):.3f}s, mean {sum(timings) / len(timings):.3f}s over {args.repeat} runs")
+
+
+if __name__ == "__main__":
+    main()
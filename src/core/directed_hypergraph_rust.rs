@@ -0,0 +1,256 @@
+use super::{error::HypergraphError, meta_handler::MetaHandler};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A directed hyperedge, made of a tail set pointing to a head set.
+pub type DirectedEdge = (Vec<usize>, Vec<usize>);
+
+/// A directed hypergraph (B-hypergraph) data structure.
+#[derive(Clone)]
+pub struct DirectedHypergraphRust {
+    /// Metadata handler for storing attributes associated with nodes and edges.
+    attr: MetaHandler<String>,
+    /// Indicates whether the hypergraph is weighted.
+    weighted: bool,
+    /// List of directed edges with their associated weights.
+    edge_list: rustc_hash::FxHashMap<DirectedEdge, f64>,
+    /// Maps each node to the IDs of the edges having it in their tail.
+    out_adj: rustc_hash::FxHashMap<usize, HashSet<usize>>,
+    /// Maps each node to the IDs of the edges having it in their head.
+    in_adj: rustc_hash::FxHashMap<usize, HashSet<usize>>,
+    /// Maps each edge index in the metadata handler to the edge.
+    id_to_edge: rustc_hash::FxHashMap<usize, DirectedEdge>,
+}
+
+impl DirectedHypergraphRust {
+    /// Creates a new directed hypergraph from an optional list of edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge_list`: The `(tail, head)` edges to be added.
+    /// * `weighted`: Whether the hypergraph is weighted.
+    /// * `weights`: The weights of the edges. Defaults to `1.0` for every edge.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the hypergraph, or an error if a tail or head is empty or `weights` and `edge_list` have different lengths.
+    pub fn new(
+        edge_list: Option<Vec<DirectedEdge>>,
+        weighted: bool,
+        weights: Option<Vec<f64>>,
+    ) -> Result<DirectedHypergraphRust, HypergraphError> {
+        let mut hypergraph = DirectedHypergraphRust {
+            attr: MetaHandler::new(),
+            weighted,
+            edge_list: rustc_hash::FxHashMap::default(),
+            out_adj: rustc_hash::FxHashMap::default(),
+            in_adj: rustc_hash::FxHashMap::default(),
+            id_to_edge: rustc_hash::FxHashMap::default(),
+        };
+
+        if let Some(edges) = edge_list {
+            let weights = weights.unwrap_or_else(|| vec![1.0; edges.len()]);
+            if weights.len() != edges.len() {
+                return Err(HypergraphError::InvalidArgument(
+                    "The number of edges and weights must be the same.".to_string(),
+                ));
+            }
+
+            for ((tail, head), weight) in edges.into_iter().zip(weights) {
+                hypergraph.add_edge(tail, head, Some(weight), None)?;
+            }
+        }
+
+        Ok(hypergraph)
+    }
+
+    /// Adds a new directed edge to the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `tail`: The nodes the edge starts from.
+    /// * `head`: The nodes the edge points to.
+    /// * `weight`: The weight of the edge. If the hypergraph is not weighted, this argument is ignored.
+    /// * `metadata`: Additional metadata associated with the edge.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the edge was added successfully.
+    /// * `Err(HypergraphError)` if the tail or the head is empty.
+    ///
+    /// # Notes
+    ///
+    /// Tail and head are stored as sorted sets. As in the undirected case, adding an existing edge
+    /// to an unweighted hypergraph increments its multiplicity, stored in place of the weight.
+    pub fn add_edge(
+        &mut self,
+        tail: Vec<usize>,
+        head: Vec<usize>,
+        weight: Option<f64>,
+        metadata: Option<HashMap<String, String>>,
+    ) -> Result<(), HypergraphError> {
+        if tail.is_empty() || head.is_empty() {
+            return Err(HypergraphError::EmptyEdge);
+        }
+
+        let edge = (normalize(tail), normalize(head));
+        if self.weighted {
+            self.edge_list.insert(edge.clone(), weight.unwrap_or(1.0));
+        } else {
+            *self.edge_list.entry(edge.clone()).or_insert(0.0) += 1.0;
+        }
+
+        let edge_idx = self.attr.add_obj(edge_name(&edge), Some("edge".to_string()), metadata);
+        self.id_to_edge.insert(edge_idx, edge.clone());
+
+        let (tail, head) = edge;
+        for &node in tail.iter().chain(head.iter()) {
+            self.add_node(node);
+        }
+        for node in tail {
+            self.out_adj.entry(node).or_default().insert(edge_idx);
+        }
+        for node in head {
+            self.in_adj.entry(node).or_default().insert(edge_idx);
+        }
+
+        Ok(())
+    }
+
+    /// Adds a node to the hypergraph, if it is not already present.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The node to be added.
+    pub fn add_node(&mut self, node: usize) {
+        if self.attr.get_id_by_object(&node.to_string()).is_none() {
+            let mut node_metadata = HashMap::new();
+            node_metadata.insert("type".to_string(), "node".to_string());
+            node_metadata.insert("name".to_string(), node.to_string());
+            self.attr.add_obj(node.to_string(), Some("node".to_string()), Some(node_metadata));
+        }
+        self.out_adj.entry(node).or_default();
+        self.in_adj.entry(node).or_default();
+    }
+
+    /// Returns all nodes in the hypergraph, sorted by ID.
+    pub fn get_nodes(&self) -> Vec<usize> {
+        let mut nodes: Vec<usize> = self.out_adj.keys().copied().collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Returns all directed edges of the hypergraph, sorted by tail and then by head.
+    pub fn get_edges(&self) -> Vec<DirectedEdge> {
+        let mut edges: Vec<DirectedEdge> = self.edge_list.keys().cloned().collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Returns the weight of a directed edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `tail`: The tail of the edge.
+    /// * `head`: The head of the edge.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the weight (the multiplicity, if unweighted), or an error if the edge is not in the hypergraph.
+    pub fn get_weight(&self, tail: Vec<usize>, head: Vec<usize>) -> Result<f64, HypergraphError> {
+        let edge = (normalize(tail), normalize(head));
+        self.edge_list
+            .get(&edge)
+            .copied()
+            .ok_or_else(|| HypergraphError::ObjectNotFound(edge_name(&edge)))
+    }
+
+    /// Returns the metadata associated with a directed edge, if any.
+    pub fn get_edge_meta(&self, tail: Vec<usize>, head: Vec<usize>) -> Option<&HashMap<String, String>> {
+        self.attr.get_attr(&edge_name(&(normalize(tail), normalize(head)))).ok()
+    }
+
+    /// Returns the number of edges having `node` in their head.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the in-degree, or an error if the node is not in the hypergraph.
+    pub fn in_degree(&self, node: usize) -> Result<usize, HypergraphError> {
+        self.in_adj.get(&node).map(HashSet::len).ok_or(HypergraphError::NodeNotFound(node))
+    }
+
+    /// Returns the number of edges having `node` in their tail.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the out-degree, or an error if the node is not in the hypergraph.
+    pub fn out_degree(&self, node: usize) -> Result<usize, HypergraphError> {
+        self.out_adj.get(&node).map(HashSet::len).ok_or(HypergraphError::NodeNotFound(node))
+    }
+
+    /// Returns the nodes B-reachable from a source node.
+    ///
+    /// A head node becomes reachable once every node in the tail of one of its incoming
+    /// edges is reachable, so an edge fires only when its whole tail has been reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: The node the search starts from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the reachable nodes, `source` included, or an error if the source is not in the hypergraph.
+    pub fn forward_reachable(&self, source: usize) -> Result<HashSet<usize>, HypergraphError> {
+        if !self.out_adj.contains_key(&source) {
+            return Err(HypergraphError::NodeNotFound(source));
+        }
+
+        let mut reached = HashSet::from([source]);
+        let mut queue = VecDeque::from([source]);
+        // Numero di nodi della coda di ciascun arco già raggiunti
+        let mut reached_in_tail: HashMap<usize, usize> = HashMap::new();
+
+        while let Some(node) = queue.pop_front() {
+            for &edge_id in &self.out_adj[&node] {
+                let (tail, head) = &self.id_to_edge[&edge_id];
+                let count = reached_in_tail.entry(edge_id).or_insert(0);
+                *count += 1;
+                if *count == tail.len() {
+                    for &next in head {
+                        if reached.insert(next) {
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(reached)
+    }
+
+    /// Returns the number of nodes in the hypergraph.
+    pub fn num_nodes(&self) -> usize {
+        self.out_adj.len()
+    }
+
+    /// Returns the number of distinct directed edges in the hypergraph.
+    pub fn num_edges(&self) -> usize {
+        self.edge_list.len()
+    }
+
+    /// Returns `true` if the hypergraph is weighted, `false` otherwise.
+    pub fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+}
+
+/// Sorts a tail or head and drops repeated nodes.
+fn normalize(mut nodes: Vec<usize>) -> Vec<usize> {
+    nodes.sort_unstable();
+    nodes.dedup();
+    nodes
+}
+
+/// Name under which a directed edge is stored in the metadata handler.
+fn edge_name(edge: &DirectedEdge) -> String {
+    format!("{:?} -> {:?}", edge.0, edge.1)
+}
@@ -0,0 +1,85 @@
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+use super::directed_hypergraph_rust::DirectedHypergraphRust;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct DirectedHypergraph {
+    pub inner: DirectedHypergraphRust,
+}
+
+#[pymethods]
+impl DirectedHypergraph {
+    #[new]
+    #[pyo3(signature = (edge_list=None, weighted=false, weights=None))]
+    pub fn new(
+        edge_list: Option<Vec<(Vec<usize>, Vec<usize>)>>,
+        weighted: bool,
+        weights: Option<Vec<f64>>,
+    ) -> PyResult<Self> {
+        let hypergraph = DirectedHypergraphRust::new(edge_list, weighted, weights)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        Ok(DirectedHypergraph { inner: hypergraph })
+    }
+
+    #[pyo3(signature = (tail, head, weight = None, metadata = None))]
+    pub fn add_edge(
+        &mut self,
+        tail: Vec<usize>,
+        head: Vec<usize>,
+        weight: Option<f64>,
+        metadata: Option<HashMap<String, String>>,
+    ) -> PyResult<()> {
+        self.inner.add_edge(tail, head, weight, metadata)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn add_node(&mut self, node: usize) {
+        self.inner.add_node(node)
+    }
+
+    pub fn get_nodes(&self) -> Vec<usize> {
+        self.inner.get_nodes()
+    }
+
+    pub fn get_edges(&self) -> Vec<(Vec<usize>, Vec<usize>)> {
+        self.inner.get_edges()
+    }
+
+    pub fn get_weight(&self, tail: Vec<usize>, head: Vec<usize>) -> PyResult<f64> {
+        self.inner.get_weight(tail, head)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn get_edge_meta(&self, tail: Vec<usize>, head: Vec<usize>) -> Option<HashMap<String, String>> {
+        self.inner.get_edge_meta(tail, head).cloned()
+    }
+
+    pub fn in_degree(&self, node: usize) -> PyResult<usize> {
+        self.inner.in_degree(node)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn out_degree(&self, node: usize) -> PyResult<usize> {
+        self.inner.out_degree(node)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn forward_reachable(&self, source: usize) -> PyResult<HashSet<usize>> {
+        self.inner.forward_reachable(source)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.inner.num_nodes()
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.inner.num_edges()
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.inner.is_weighted()
+    }
+}
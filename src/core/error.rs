@@ -0,0 +1,87 @@
+use std::fmt;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyErr;
+
+/// Errors returned by the hypergraph core, measures and generators.
+///
+/// Some errors come wrapped in `WithMessage`; match on `kind()` rather than on the error itself.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HypergraphError {
+    /// An edge without nodes was given.
+    EmptyEdge,
+    /// The edge is not in the hypergraph.
+    EdgeNotFound(Vec<usize>),
+    /// The node is not in the hypergraph.
+    NodeNotFound(usize),
+    /// No metadata object with this identifier exists.
+    ObjectNotFound(String),
+    /// The object exists but has no such attribute.
+    AttributeNotFound { obj: usize, attr: String },
+    /// The operation needs at least one edge or node.
+    EmptyHypergraph,
+    /// The operation needs a uniform hypergraph.
+    NotUniform,
+    /// The operation needs a connected hypergraph.
+    NotConnected,
+    /// An iterative method reached the maximum number of iterations.
+    NotConverged,
+    /// An argument is out of range or inconsistent with the others.
+    InvalidArgument(String),
+    /// An error of another kind, reported with a message of its own instead of the default text.
+    /// Its kind is returned by `HypergraphError::kind`.
+    WithMessage { kind: Box<HypergraphError>, message: String },
+}
+
+impl HypergraphError {
+    /// Keeps the kind of the error but reports it with `message`.
+    ///
+    /// Some call sites had their own wording before `HypergraphError` existed; they keep it, so
+    /// the text raised in Python does not change.
+    pub fn with_message(self, message: impl Into<String>) -> Self {
+        // Un errore già riformulato cambia solo il messaggio, così il tipo resta a un livello
+        let kind = match self {
+            HypergraphError::WithMessage { kind, .. } => kind,
+            other => Box::new(other),
+        };
+        HypergraphError::WithMessage { kind, message: message.into() }
+    }
+
+    /// Returns the kind of the error, looking through `WithMessage`.
+    ///
+    /// `matches!(err.kind(), HypergraphError::NotUniform)` holds for every function returning a
+    /// non-uniform error, whether or not it reports it with a message of its own.
+    pub fn kind(&self) -> &HypergraphError {
+        match self {
+            HypergraphError::WithMessage { kind, .. } => kind,
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for HypergraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HypergraphError::EmptyEdge => write!(f, "Empty edge not allowed."),
+            HypergraphError::EdgeNotFound(edge) => write!(f, "Edge {:?} not in hypergraph.", edge),
+            HypergraphError::NodeNotFound(node) => write!(f, "Node {} not found in hypergraph.", node),
+            HypergraphError::ObjectNotFound(obj) => write!(f, "Object ID {} not found in hypergraph", obj),
+            HypergraphError::AttributeNotFound { obj, attr } => {
+                write!(f, "Attribute '{}' not found for object {}", attr, obj)
+            }
+            HypergraphError::EmptyHypergraph => write!(f, "The hypergraph is empty."),
+            HypergraphError::NotUniform => write!(f, "The hypergraph is not uniform."),
+            HypergraphError::NotConnected => write!(f, "The hypergraph is not connected."),
+            HypergraphError::NotConverged => write!(f, "Power iteration did not converge."),
+            HypergraphError::InvalidArgument(msg) => write!(f, "{}", msg),
+            HypergraphError::WithMessage { message, .. } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for HypergraphError {}
+
+impl From<HypergraphError> for PyErr {
+    fn from(err: HypergraphError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
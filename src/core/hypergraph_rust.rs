@@ -1,5 +1,16 @@
-use super::{label_encoder::LabelEncoder, meta_handler::MetaHandler};
+use super::{error::HypergraphError, label_encoder::LabelEncoder, meta_handler::MetaHandler};
 use std::collections::{HashMap, HashSet, BTreeMap};
+use rand::Rng;
+use super::rng::rng_from_seed;
+
+/// Largest number of nodes for which `is_isomorphic` attempts the exact backtracking search.
+const MAX_ISOMORPHISM_NODES: usize = 16;
+
+/// Number of attempts allowed for each requested swap in `double_edge_swap`.
+const MAX_SWAP_ATTEMPTS: usize = 100;
+
+/// Largest difference allowed between `matrix[i][j]` and `matrix[j][i]` in `from_adjacency_matrix`.
+const SYMMETRY_TOLERANCE: f64 = 1e-9;
 
 /// A hypergraph data structure.
 #[derive(Clone)]
@@ -16,27 +27,41 @@ pub struct HypergraphRust {
     max_order: usize,
     /// List of edges with their associated weights.
     pub edge_list: rustc_hash::FxHashMap<Vec<usize>, f64>,
+    /// Maps each edge index in the metadata handler to the nodes of the edge.
+    id_to_edge: rustc_hash::FxHashMap<usize, Vec<usize>>,
+    /// Optional vectors of numeric attributes attached to the edges, all of the same length.
+    edge_weights_vec: rustc_hash::FxHashMap<Vec<usize>, Vec<f64>>,
 }
 
 impl HypergraphRust {
+    /// Creates a new hypergraph from an optional list of edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge_list`: The edges to be added.
+    /// * `weighted`: Whether the hypergraph is weighted.
+    /// * `weights`: The weights of the edges. Defaults to `1.0` for every edge.
+    /// * `metadata`: Additional metadata for the edges, keyed by the position of the edge in `edge_list`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the hypergraph, or an error if an edge is empty or `weights` and `edge_list` have different lengths.
     pub fn new(
         edge_list: Option<Vec<Vec<usize>>>,
         weighted: bool,
         weights: Option<Vec<f64>>,
         metadata: Option<HashMap<String, String>>,
-    ) -> HypergraphRust {
-        let mut hypergraph = HypergraphRust {
-            attr: MetaHandler::new(),
-            weighted,
-            edges_by_order: BTreeMap::new(),
-            adj: rustc_hash::FxHashMap::default(),
-            max_order: 0,
-            edge_list: rustc_hash::FxHashMap::default(),
-        };
+    ) -> Result<HypergraphRust, HypergraphError> {
+        let mut hypergraph = HypergraphRust::empty(weighted);
 
         if let Some(edges) = edge_list {
             let default_weights = vec![1.0; edges.len()];
             let weights = weights.unwrap_or(default_weights);
+            if weights.len() != edges.len() {
+                return Err(HypergraphError::InvalidArgument(
+                    "The number of edges and weights must be the same.".to_string(),
+                ));
+            }
 
             for (i, edge) in edges.iter().enumerate() {
                 let mut edge_metadata_map = HashMap::new();
@@ -49,11 +74,183 @@ impl HypergraphRust {
                     }
                 }
 
-                hypergraph.add_edge(edge.clone(), Some(weights[i]), Some(edge_metadata_map)).unwrap();
+                hypergraph.add_edge(edge.clone(), Some(weights[i]), Some(edge_metadata_map))?;
+            }
+        }
+
+        Ok(hypergraph)
+    }
+
+    /// Creates a hypergraph without nodes or edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `weighted`: Whether the hypergraph is weighted.
+    pub fn empty(weighted: bool) -> HypergraphRust {
+        HypergraphRust {
+            attr: MetaHandler::new(),
+            weighted,
+            edges_by_order: BTreeMap::new(),
+            adj: rustc_hash::FxHashMap::default(),
+            max_order: 0,
+            edge_list: rustc_hash::FxHashMap::default(),
+            id_to_edge: rustc_hash::FxHashMap::default(),
+            edge_weights_vec: rustc_hash::FxHashMap::default(),
+        }
+    }
+
+    /// Creates an empty hypergraph pre-sized for the given number of nodes and edges.
+    ///
+    /// Loading a hypergraph of known size edge by edge then avoids rehashing the internal maps.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_nodes`: The expected number of nodes.
+    /// * `num_edges`: The expected number of distinct edges.
+    /// * `weighted`: Whether the hypergraph is weighted.
+    pub fn with_capacity(num_nodes: usize, num_edges: usize, weighted: bool) -> HypergraphRust {
+        HypergraphRust {
+            attr: MetaHandler::with_capacity(num_nodes + num_edges),
+            weighted,
+            edges_by_order: BTreeMap::new(),
+            adj: rustc_hash::FxHashMap::with_capacity_and_hasher(num_nodes, Default::default()),
+            max_order: 0,
+            edge_list: rustc_hash::FxHashMap::with_capacity_and_hasher(num_edges, Default::default()),
+            id_to_edge: rustc_hash::FxHashMap::with_capacity_and_hasher(num_edges, Default::default()),
+            edge_weights_vec: rustc_hash::FxHashMap::default(),
+        }
+    }
+
+    /// Creates a hypergraph from a dense node-edge incidence matrix.
+    ///
+    /// This is the inverse of `incidence_csr`: row `i` is the node `node_ids[i]`, every column is
+    /// an edge made of the rows holding a `1` in it. Nodes whose row is all zeros are kept as
+    /// isolated nodes. Identical columns are parallel copies of the same edge and are joined: their
+    /// weights are summed, or, if `weighted_values` is not given, they count towards the multiplicity.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The incidence matrix, one row per node and one column per edge, with entries `0` or `1`.
+    /// * `node_ids`: The ID of the node of each row.
+    /// * `weighted_values`: The weight of each column. If given, the hypergraph is weighted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the hypergraph, or an error if the dimensions of `matrix`, `node_ids`
+    /// and `weighted_values` do not match, if a node ID is repeated, if an entry is not `0` or `1`, or if a
+    /// column is all zeros.
+    pub fn from_incidence_matrix(
+        matrix: Vec<Vec<u8>>,
+        node_ids: Vec<usize>,
+        weighted_values: Option<Vec<f64>>,
+    ) -> Result<HypergraphRust, HypergraphError> {
+        if matrix.len() != node_ids.len() {
+            return Err(HypergraphError::InvalidArgument(
+                "The number of rows and node IDs must be the same.".to_string(),
+            ));
+        }
+        if node_ids.iter().collect::<HashSet<_>>().len() != node_ids.len() {
+            return Err(HypergraphError::InvalidArgument("Node IDs must be unique.".to_string()));
+        }
+        let num_edges = matrix.first().map_or(0, |row| row.len());
+        if matrix.iter().any(|row| row.len() != num_edges) {
+            return Err(HypergraphError::InvalidArgument(
+                "All rows of the incidence matrix must have the same length.".to_string(),
+            ));
+        }
+        if matrix.iter().flatten().any(|&entry| entry > 1) {
+            return Err(HypergraphError::InvalidArgument(
+                "Incidence matrix entries must be 0 or 1.".to_string(),
+            ));
+        }
+
+        let mut edges = vec![Vec::new(); num_edges];
+        for (row, &node) in matrix.iter().zip(&node_ids) {
+            for (col, &entry) in row.iter().enumerate() {
+                if entry == 1 {
+                    edges[col].push(node);
+                }
+            }
+        }
+        if let Some(col) = edges.iter().position(|edge| edge.is_empty()) {
+            return Err(HypergraphError::InvalidArgument(format!(
+                "Column {} of the incidence matrix has no nonzero entry.", col
+            )));
+        }
+
+        let mut hypergraph = match weighted_values {
+            Some(values) => {
+                if values.len() != num_edges {
+                    return Err(HypergraphError::InvalidArgument(
+                        "The number of edges and weights must be the same.".to_string(),
+                    ));
+                }
+                // Le colonne identiche sono copie dello stesso arco: se ne sommano i pesi
+                let mut hypergraph = HypergraphRust::empty(true);
+                for (mut edge, weight) in edges.into_iter().zip(values) {
+                    edge.sort_unstable();
+                    let previous = hypergraph.edge_list.get(&edge).copied().unwrap_or(0.0);
+                    hypergraph.add_edge(edge.clone(), Some(weight), None)?;
+                    hypergraph.edge_list.insert(edge, previous + weight);
+                }
+                hypergraph
+            }
+            None => HypergraphRust::new(Some(edges), false, None, None)?,
+        };
+        hypergraph.add_nodes(node_ids);
+        Ok(hypergraph)
+    }
+
+    /// Creates a weighted hypergraph of size-2 edges from a dense adjacency matrix.
+    ///
+    /// This lifts an ordinary weighted graph, such as the one returned by `adjacency_matrix`: every
+    /// pair `i < j` whose entry is above `threshold` becomes the edge `[node_ids[i], node_ids[j]]`
+    /// with the entry as weight. The diagonal is ignored and every node is kept, even if isolated.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The square, symmetric adjacency matrix.
+    /// * `node_ids`: The ID of the node of each row.
+    /// * `threshold`: Entries must be strictly greater than this value to create an edge.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the hypergraph, or an error if `matrix` is not square, if its size does
+    /// not match `node_ids`, if a node ID is repeated, or if the matrix is not symmetric.
+    pub fn from_adjacency_matrix(
+        matrix: Vec<Vec<f64>>,
+        node_ids: Vec<usize>,
+        threshold: f64,
+    ) -> Result<HypergraphRust, HypergraphError> {
+        let size = node_ids.len();
+        if matrix.len() != size || matrix.iter().any(|row| row.len() != size) {
+            return Err(HypergraphError::InvalidArgument(
+                "The adjacency matrix must be square, with one row per node ID.".to_string(),
+            ));
+        }
+        if node_ids.iter().collect::<HashSet<_>>().len() != size {
+            return Err(HypergraphError::InvalidArgument("Node IDs must be unique.".to_string()));
+        }
+
+        let mut edges = Vec::new();
+        let mut weights = Vec::new();
+        for i in 0..size {
+            for j in (i + 1)..size {
+                if (matrix[i][j] - matrix[j][i]).abs() > SYMMETRY_TOLERANCE {
+                    return Err(HypergraphError::InvalidArgument(format!(
+                        "The adjacency matrix is not symmetric at ({}, {}).", i, j
+                    )));
+                }
+                if matrix[i][j] > threshold {
+                    edges.push(vec![node_ids[i], node_ids[j]]);
+                    weights.push(matrix[i][j]);
+                }
             }
         }
 
-        hypergraph
+        let mut hypergraph = HypergraphRust::new(Some(edges), true, Some(weights), None)?;
+        hypergraph.add_nodes(node_ids);
+        Ok(hypergraph)
     }
 
     /// Adds a new edge to the hypergraph.
@@ -67,17 +264,32 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the edge was added successfully.
-    /// * `Err(String)` if the edge could not be added. This can happen if the hypergraph is weighted and no weight is provided, or if the hypergraph is not weighted and a weight is provided.
+    /// * `Err(HypergraphError)` if the edge could not be added. This can happen if the edge is empty, if the hypergraph is weighted and no weight is provided, or if the hypergraph is not weighted and a weight is provided.
+    ///
+    /// # Notes
+    ///
+    /// Edges are stored as sets: nodes are sorted and repeated nodes are dropped, so `[1, 1, 2]`
+    /// is stored as the order-1 edge `[1, 2]`. The same normalization is applied by every method
+    /// that looks up an edge (`check_edge`, `remove_edge`, `get_weight`, ...).
+    ///
+    /// In an unweighted hypergraph, adding an existing edge again increments its multiplicity,
+    /// which is stored in place of the weight. The edge is still counted once by `num_edges`
+    /// unless `multiplicity` is requested.
     pub fn add_edge(
         &mut self,
         edge: Vec<usize>,
         weight: Option<f64>,
         metadata: Option<HashMap<String, String>>,
-    ) -> Result<(), String> {
+    ) -> Result<(), HypergraphError> {
+        if edge.is_empty() {
+            return Err(HypergraphError::EmptyEdge);
+        }
+
         // Pre-allocare il vettore con la dimensione corretta
         let mut sorted_edge = Vec::with_capacity(edge.len());
         sorted_edge.extend_from_slice(&edge);
         sorted_edge.sort_unstable(); // sort_unstable è più veloce di sort
+        sorted_edge.dedup();
 
         // Usa entry API per ridurre i lookup
         self.edges_by_order
@@ -95,6 +307,7 @@ impl HypergraphRust {
         let edge_str = format!("{:?}", sorted_edge);
     
         let edge_idx = self.attr.add_obj(edge_str.clone(), Some("edge".to_string()), metadata);
+        self.id_to_edge.insert(edge_idx, sorted_edge.clone());
     
         let order = sorted_edge.len() - 1;
         self.max_order = self.max_order.max(order);
@@ -128,57 +341,100 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the edges were added successfully.
-    /// * `Err(String)` if the edges could not be added. This can happen if the hypergraph is weighted and no weights are provided, or if the hypergraph is not weighted and weights are provided.
+    /// * `Err(HypergraphError)` if the edges could not be added. This can happen if the hypergraph is weighted and no weights are provided, or if the hypergraph is not weighted and weights are provided.
     pub fn add_edges(
         &mut self,
         edges: Vec<Vec<usize>>,
         weights: Option<Vec<f64>>,
         metadata: Option<HashMap<String, String>>,
-    ) -> Result<(), String> {
-        match (self.weighted, &weights) {
-            (true, None) => return Err("Weights must be provided for a weighted hypergraph.".to_string()),
-            (false, Some(_)) => return Err("Weights should not be provided for an unweighted hypergraph.".to_string()),
-            _ => {}
-        }
+    ) -> Result<(), HypergraphError> {
+        self.check_weights_argument(weights.is_some())?;
     
         if let Some(ref w) = weights {
             if w.len() != edges.len() {
-                return Err("The number of edges and weights must be the same.".to_string());
+                return Err(HypergraphError::InvalidArgument("The number of edges and weights must be the same.".to_string()));
             }
         }
     
         for (i, edge) in edges.into_iter().enumerate() {
-            let mut edge_metadata_map = HashMap::new();
-            edge_metadata_map.insert("type".to_string(), "edge".to_string());
-            edge_metadata_map.insert("name".to_string(), format!("{:?}", edge));
-    
-            if let Some(ref meta) = metadata {
-                if let Some(meta_value) = meta.get(&i.to_string()) {
-                    edge_metadata_map.insert(i.to_string(), meta_value.clone());
-                }
-            }
-    
             let weight = weights.as_ref().map(|w| w[i]);
+            self.add_indexed_edge(i, edge, weight, metadata.as_ref())?;
+        }
     
-            // Gestisci ogni nodo dell'edge
-            for &node in &edge {
-                if self.attr.get_id_by_object(&node.to_string()).is_none() {
-                    let mut node_metadata = HashMap::new();
-                    node_metadata.insert("type".to_string(), "node".to_string());
-                    node_metadata.insert("name".to_string(), node.to_string());
-                    self.attr.add_obj(node.to_string(), Some("node".to_string()), Some(node_metadata));
-                }
-                self.add_node(node);
+        Ok(())
+    }
+
+    /// Checks that weights are given if and only if the hypergraph is weighted.
+    ///
+    /// # Arguments
+    ///
+    /// * `has_weights`: Whether the caller provided edge weights.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the weights match the kind of hypergraph, an `InvalidArgument` error otherwise.
+    pub fn check_weights_argument(&self, has_weights: bool) -> Result<(), HypergraphError> {
+        match (self.weighted, has_weights) {
+            (true, false) => Err(HypergraphError::InvalidArgument("Weights must be provided for a weighted hypergraph.".to_string())),
+            (false, true) => Err(HypergraphError::InvalidArgument("Weights should not be provided for an unweighted hypergraph.".to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Adds the `index`-th edge of a batch, as done by `add_edges`.
+    ///
+    /// The nodes of the edge are registered in the metadata handler, and the edge gets the default
+    /// `type`/`name` metadata plus the entry of `metadata` keyed by `index`, if any. Adding an
+    /// existing edge increases its multiplicity in unweighted hypergraphs and updates its weight otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: The position of the edge in the batch.
+    /// * `edge`: The edge to be added.
+    /// * `weight`: The weight of the edge, required if the hypergraph is weighted.
+    /// * `metadata`: The per-batch metadata, keyed by edge position.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the edge was added successfully, or the error of `add_edge` / `update_edge`.
+    pub fn add_indexed_edge(
+        &mut self,
+        index: usize,
+        edge: Vec<usize>,
+        weight: Option<f64>,
+        metadata: Option<&HashMap<String, String>>,
+    ) -> Result<(), HypergraphError> {
+        let mut edge_metadata_map = HashMap::new();
+        edge_metadata_map.insert("type".to_string(), "edge".to_string());
+        edge_metadata_map.insert("name".to_string(), format!("{:?}", edge));
+
+        if let Some(meta_value) = metadata.and_then(|meta| meta.get(&index.to_string())) {
+            edge_metadata_map.insert(index.to_string(), meta_value.clone());
+        }
+
+        // Gestisci ogni nodo dell'edge
+        for &node in &edge {
+            if self.attr.get_id_by_object(&node.to_string()).is_none() {
+                let mut node_metadata = HashMap::new();
+                node_metadata.insert("type".to_string(), "node".to_string());
+                node_metadata.insert("name".to_string(), node.to_string());
+                self.attr.add_obj(node.to_string(), Some("node".to_string()), Some(node_metadata));
             }
-    
-            if self.edge_exists(&edge) {
-                self.update_edge(edge, weight, Some(edge_metadata_map))?;
-            } else {
-                self.add_edge(edge, weight, Some(edge_metadata_map))?;
+            self.add_node(node);
+        }
+
+        if self.edge_exists(&edge) {
+            if !self.weighted {
+                // Negli ipergrafi non pesati un duplicato incrementa la molteplicità
+                let mut sorted_edge = edge.clone();
+                sorted_edge.sort_unstable();
+                sorted_edge.dedup();
+                *self.edge_list.entry(sorted_edge).or_insert(0.0) += 1.0;
             }
+            self.update_edge(edge, weight, Some(edge_metadata_map))
+        } else {
+            self.add_edge(edge, weight, Some(edge_metadata_map))
         }
-    
-        Ok(())
     }
 
     /// Verifica se un arco esiste nel grafo.
@@ -194,6 +450,7 @@ impl HypergraphRust {
     fn edge_exists(&self, edge: &Vec<usize>) -> bool {
         let mut sorted_edge = edge.clone();
         sorted_edge.sort_unstable();
+        sorted_edge.dedup();
         
         self.edge_list.contains_key(&sorted_edge)
     }
@@ -209,15 +466,16 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the edge was updated successfully.
-    /// * `Err(String)` if the edge does not exist in the hypergraph.
+    /// * `Err(HypergraphError)` if the edge does not exist in the hypergraph.
     pub fn update_edge(
         &mut self,
         edge: Vec<usize>,
         weight: Option<f64>,
         metadata: Option<HashMap<String, String>>
-    ) -> Result<(), String> {
+    ) -> Result<(), HypergraphError> {
         let mut sorted_edge = edge.clone();
         sorted_edge.sort_unstable(); // Assicurati di ordinare lo spigolo
+        sorted_edge.dedup();
     
         // Controlla se l'arco esiste
         if self.edge_exists(&sorted_edge) {
@@ -235,7 +493,7 @@ impl HypergraphRust {
     
             Ok(())
         } else {
-            Err("Edge does not exist.".to_string())
+            Err(HypergraphError::EdgeNotFound(edge).with_message("Edge does not exist."))
         }
     }
 
@@ -293,7 +551,7 @@ impl HypergraphRust {
     /// associated metadata.
     pub fn get_nodes_with_metadata(&self) -> Vec<(usize, HashMap<String, String>)> {
         self.adj.keys().filter_map(|&node| {
-            if let Some(attributes) = self.attr.get_attributes(node) {
+            if let Ok(attributes) = self.attr.get_attr(&node.to_string()) {
                 if attributes.get("type") == Some(&"node".to_string()) {
                     Some((node, attributes.clone()))
                 } else {
@@ -306,6 +564,52 @@ impl HypergraphRust {
         }).collect()
     }
 
+    /// Returns the adjacency structure of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A `HashMap` from each node to the sorted IDs (in the metadata handler) of its incident edges.
+    /// Isolated nodes map to an empty vector.
+    pub fn get_adjacency(&self) -> HashMap<usize, Vec<usize>> {
+        self.adj
+            .iter()
+            .map(|(&node, edge_ids)| {
+                let mut ids: Vec<usize> = edge_ids.iter().copied().collect();
+                ids.sort_unstable();
+                (node, ids)
+            })
+            .collect()
+    }
+
+    /// Returns the metadata of every node, keyed by node ID.
+    ///
+    /// # Returns
+    ///
+    /// A `HashMap` from each node ID to its attributes, built from `get_nodes_with_metadata`.
+    pub fn get_all_node_metadata(&self) -> HashMap<usize, HashMap<String, String>> {
+        self.get_nodes_with_metadata().into_iter().collect()
+    }
+
+    /// Returns the nodes whose metadata contains the given key/value pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: The metadata key to look up.
+    /// * `value`: The value the key must be associated with.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector with the IDs of the matching nodes.
+    pub fn nodes_with_meta(&self, key: String, value: String) -> Vec<usize> {
+        let mut nodes: Vec<usize> = self.get_nodes_with_metadata()
+            .into_iter()
+            .filter(|(_, meta)| meta.get(&key) == Some(&value))
+            .map(|(node, _)| node)
+            .collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
     /// Returns the metadata associated with the given object ID.
     ///
     /// # Arguments
@@ -350,17 +654,18 @@ impl HypergraphRust {
     ///
     /// A vector of references to the edges in the hypergraph that match the
     /// specified criteria, or an error message if `order` and `size` are both
-    /// specified.
+    /// specified. For the same filters, `get_weights` and `get_edges_with_weights`
+    /// list the edges in this same order.
     pub fn get_edges(
         &self,
         _ids: bool,
         order: Option<usize>,
         size: Option<usize>,
         up_to: bool,
-    ) -> Result<Vec<&Vec<usize>>, String> {
+    ) -> Result<Vec<&Vec<usize>>, HypergraphError> {
         // Verifica immediata per condizioni errate
         if order.is_some() && size.is_some() {
-            return Err("Order and size cannot both be specified.".to_string());
+            return Err(HypergraphError::InvalidArgument("Order and size cannot both be specified.".to_string()));
         }
 
         // Caso semplice: né `order` né `size` sono specificati
@@ -391,6 +696,32 @@ impl HypergraphRust {
         Ok(edges)
     }
 
+    /// Returns the edges of the hypergraph paired with their weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: If specified, returns only edges with this order.
+    /// * `size`: If specified, returns only edges with this size (i.e., number of
+    ///   nodes).
+    /// * `up_to`: If `true`, returns all edges with orders up to and including
+    ///   `order`, or up to and including `size - 1` if `size` is specified.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(edge, weight)` pairs, filtered and ordered as in `get_edges`,
+    /// or an error message if `order` and `size` are both specified.
+    pub fn get_edges_with_weights(
+        &self,
+        order: Option<usize>,
+        size: Option<usize>,
+        up_to: bool,
+    ) -> Result<Vec<(Vec<usize>, f64)>, HypergraphError> {
+        Ok(self.get_edges(false, order, size, up_to)?
+            .into_iter()
+            .map(|edge| (edge.clone(), self.edge_list[edge]))
+            .collect())
+    }
+
     /// Returns all edges in the hypergraph, along with their associated metadata.
     ///
     /// # Returns
@@ -412,6 +743,30 @@ impl HypergraphRust {
             .collect()
     }
 
+    /// Returns the edges whose metadata contains the given key/value pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: The metadata key to look up.
+    /// * `value`: The value the key must be associated with.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector with the matching edges.
+    pub fn filter_edges_by_meta(&self, key: String, value: String) -> Vec<Vec<usize>> {
+        let mut edges: Vec<Vec<usize>> = self.edge_list
+            .keys()
+            .filter(|edge| {
+                self.attr
+                    .get_attr(&format!("{:?}", edge))
+                    .is_ok_and(|meta| meta.get(&key) == Some(&value))
+            })
+            .cloned()
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
     /// Returns `true` if the hypergraph is weighted, `false` otherwise.
     ///
     /// # Returns
@@ -430,40 +785,50 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the edge was removed successfully.
-    /// * `Err(String)` if the edge does not exist in the hypergraph.
-    pub fn remove_edge(&mut self, edge: Vec<usize>) -> Result<(), String> {
+    /// * `Err(HypergraphError)` if the edge does not exist in the hypergraph.
+    ///
+    /// # Notes
+    ///
+    /// The nodes of the edge are kept, even if they are left without incident edges. Earlier versions
+    /// dropped such nodes from the adjacency (but not from the metadata); now they stay as isolated
+    /// nodes, so `is_connected_rust` returns `false` and the measures that need a connected
+    /// hypergraph (CEC, ZEC, HEC, `spectral_embedding`, `stationary_distribution`, ...) reject it
+    /// until `remove_isolated_nodes` is called.
+    pub fn remove_edge(&mut self, edge: Vec<usize>) -> Result<(), HypergraphError> {
         let mut sorted_edge = edge.clone();
         sorted_edge.sort_unstable();
+        sorted_edge.dedup();
         let edge_str = format!("{:?}", sorted_edge);
     
         if let Some(edge_id) = self.attr.get_id_by_object(&edge_str) {
             // Rimuovi lo spigolo dalla lista degli spigoli
             self.edge_list.remove(&sorted_edge);
+            self.edge_weights_vec.remove(&sorted_edge);
     
             let order = sorted_edge.len() - 1;
             if let Some(order_edges) = self.edges_by_order.get_mut(&order) {
                 order_edges.remove(&sorted_edge);
                 if order_edges.is_empty() {
                     self.edges_by_order.remove(&order);
+                    // L'ordine massimo potrebbe essere cambiato
+                    self.max_order = self.edges_by_order.keys().next_back().copied().unwrap_or(0);
                 }
             }
     
-            // Rimuovi lo spigolo dalle adiacenze dei nodi
+            // Rimuovi lo spigolo dalle adiacenze dei nodi (i nodi restano, eventualmente isolati)
             for node in &sorted_edge {
                 if let Some(adj_edges) = self.adj.get_mut(node) {
                     adj_edges.remove(&edge_id);
-                    if adj_edges.is_empty() {
-                        self.adj.remove(node);
-                    }
                 }
             }
     
             // Rimuovi l'oggetto corrispondente dai metadati
+            self.id_to_edge.remove(edge_id);
             let _ = self.attr.remove_object(&edge_str);
     
             Ok(())
         } else {
-            Err("Edge not found in hypergraph".to_string())
+            Err(HypergraphError::EdgeNotFound(edge).with_message("Edge not found in hypergraph"))
         }
     }
 
@@ -476,97 +841,339 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the edges were removed successfully.
-    /// * `Err(String)` if any of the edges do not exist in the hypergraph.
+    /// * `Err(HypergraphError)` if any of the edges do not exist in the hypergraph.
     pub fn remove_edges(&mut self, edges: Vec<Vec<usize>>) {
         for edge in edges {
             let _ = self.remove_edge(edge);
         }
     }
 
+    /// Removes, in place, every edge whose size is not among the given sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sizes`: The edge sizes to keep. Nodes left without edges stay in the hypergraph as isolated nodes.
+    pub fn restrict_to_sizes(&mut self, sizes: Vec<usize>) {
+        let keep: HashSet<usize> = sizes.into_iter().collect();
+        let to_remove: Vec<Vec<usize>> = self.edges_by_order
+            .iter()
+            .filter(|(order, _)| !keep.contains(&(*order + 1)))
+            .flat_map(|(_, edges)| edges.iter().cloned())
+            .collect();
+
+        self.remove_edges(to_remove);
+    }
+
+    /// Removes every edge whose weight is strictly below a threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_weight`: The minimum weight an edge must have to be kept.
+    /// * `remove_isolated`: If `true`, nodes left without incident edges by the pruning are removed as well.
+    pub fn prune_by_weight(&mut self, min_weight: f64, remove_isolated: bool) {
+        let to_remove: Vec<Vec<usize>> = self.edge_list
+            .iter()
+            .filter(|(_, &weight)| weight < min_weight)
+            .map(|(edge, _)| edge.clone())
+            .collect();
+
+        let mut touched_nodes = HashSet::new();
+        for edge in to_remove {
+            touched_nodes.extend(edge.iter().copied());
+            let _ = self.remove_edge(edge);
+        }
+
+        if remove_isolated {
+            for node in touched_nodes {
+                if self.adj.get(&node).is_some_and(|edges| edges.is_empty()) {
+                    let _ = self.remove_node(node, false);
+                }
+            }
+        }
+    }
+
     /// Removes a node from the hypergraph.
     ///
     /// # Arguments
     ///
     /// * `node`: The node to be removed.
     /// * `keep_edges`: A boolean indicating whether to keep the edges associated with the node.
+    ///   If `true`, every incident edge is rewritten without the node, keeping its weight and
+    ///   metadata (edges that become empty are dropped). A rewritten edge that already exists gets
+    ///   the sum of the two weights and weight vectors, as in `contract_nodes`. If `false`, incident
+    ///   edges are removed.
     ///
     /// # Returns
     ///
     /// * `Ok(())` if the node was removed successfully.
-    /// * `Err(String)` if the node does not exist in the hypergraph.
+    /// * `Err(HypergraphError)` if the node does not exist in the hypergraph.
     pub fn remove_node(
         &mut self,
         node: usize,
         keep_edges: bool,
-    ) -> Result<(), String> {
+    ) -> Result<(), HypergraphError> {
         if let Some(edges) = self.adj.remove(&node) {
-            if !keep_edges {
-                for edge_id in edges {
-                    if let Some(edge_str) = self.attr.get_object_by_id(edge_id) {
-                        let edge: Vec<usize> = edge_str[1..edge_str.len() - 1]
-                            .split(", ")
-                            .filter_map(|s| s.parse().ok())
-                            .collect();
-                        // Rimuovi lo spigolo associato
-                        let _ = self.remove_edge(edge);
+            for edge_id in edges {
+                if let Some(edge) = self.id_to_edge.get(&edge_id).cloned() {
+                    let edge_str = format!("{:?}", edge);
+                    let weight = self.edge_list.get(&edge).copied();
+                    let mut edge_meta = self.attr.get_attr(&edge_str).ok().cloned();
+                    let weight_vec = self.edge_weights_vec.get(&edge).cloned();
+
+                    // Rimuovi lo spigolo associato
+                    self.remove_edge(edge.clone())?;
+
+                    if keep_edges {
+                        // Riscrivi lo spigolo senza il nodo rimosso
+                        let reduced_edge: Vec<usize> = edge.into_iter().filter(|&n| n != node).collect();
+                        if reduced_edge.is_empty() {
+                            continue;
+                        }
+                        if let Some(meta) = edge_meta.as_mut() {
+                            meta.insert("name".to_string(), format!("{:?}", reduced_edge));
+                        }
+                        // Come in `contract_nodes`, se lo spigolo ridotto esiste già i pesi
+                        // (o le molteplicità) si sommano
+                        let previous = self.edge_list.get(&reduced_edge).copied().unwrap_or(0.0);
+                        self.add_edge(reduced_edge.clone(), weight, edge_meta)?;
+                        if let Some(w) = weight {
+                            self.edge_list.insert(reduced_edge.clone(), previous + w);
+                        }
+                        if let Some(values) = weight_vec {
+                            self.merge_weight_vec(reduced_edge, values);
+                        }
                     }
                 }
             }
             // Rimuovi il nodo dal gestore degli attributi
-            self.attr.remove_object(&node.to_string()).map_err(|e| e.to_string())?;
+            self.attr.remove_object(&node.to_string())?;
             Ok(())
         } else {
-            Err(format!("Node {} not found in hypergraph.", node))
+            Err(HypergraphError::NodeNotFound(node))
         }
     }
 
-    /// Removes multiple nodes from the hypergraph.
+    /// Randomizes the hypergraph while preserving the degree of every node and the size of every edge.
+    ///
+    /// Each swap picks two edges `e1`, `e2` and two nodes `u` in `e1` but not in `e2` and `v` in `e2`
+    /// but not in `e1`, and exchanges them: `u` moves to `e2` and `v` to `e1`. Swaps that would create
+    /// an edge already in the hypergraph are rejected and retried.
     ///
     /// # Arguments
     ///
-    /// * `nodes`: The nodes to be removed.
-    /// * `keep_edges`: A boolean indicating whether to keep the edges associated with the nodes.
+    /// * `num_swaps`: The number of swaps to perform.
+    /// * `seed`: An optional seed for the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of swaps performed, which is lower than `num_swaps` if
+    /// `MAX_SWAP_ATTEMPTS` attempts per swap were not enough, or an error if the hypergraph has fewer than two edges.
     ///
     /// # Notes
     ///
-    /// The function is idempotent, i.e., attempting to remove a node that is not in the hypergraph
-    /// does not result in an error.
-    pub fn remove_nodes(
-        &mut self,
-        nodes: Vec<usize>,
-        keep_edges: bool
-    ) {
-        for node in nodes {
-            let _ = self.remove_node(node, keep_edges);
+    /// Swapped edges keep their weight (or multiplicity) but lose their metadata.
+    pub fn double_edge_swap(&mut self, num_swaps: usize, seed: Option<u64>) -> Result<usize, HypergraphError> {
+        if self.edge_list.len() < 2 {
+            return Err(HypergraphError::InvalidArgument("At least two edges are needed to swap nodes.".to_string()));
+        }
+
+        let mut rng = rng_from_seed(seed);
+
+        // Ordiniamo gli archi per rendere gli scambi riproducibili con lo stesso seed
+        let mut edges: Vec<Vec<usize>> = self.edge_list.keys().cloned().collect();
+        edges.sort_unstable();
+
+        let swapped = |edge: &[usize], out: usize, into: usize| -> Vec<usize> {
+            let mut new_edge: Vec<usize> = edge.iter().map(|&node| if node == out { into } else { node }).collect();
+            new_edge.sort_unstable();
+            new_edge
+        };
+
+        let mut performed = 0;
+        let mut attempts = 0;
+        while performed < num_swaps && attempts < num_swaps.saturating_mul(MAX_SWAP_ATTEMPTS) {
+            attempts += 1;
+
+            let (i, j) = (rng.gen_range(0..edges.len()), rng.gen_range(0..edges.len()));
+            if i == j {
+                continue;
+            }
+            let only_i: Vec<usize> = edges[i].iter().copied().filter(|node| edges[j].binary_search(node).is_err()).collect();
+            let only_j: Vec<usize> = edges[j].iter().copied().filter(|node| edges[i].binary_search(node).is_err()).collect();
+            if only_i.is_empty() || only_j.is_empty() {
+                continue;
+            }
+
+            let u = only_i[rng.gen_range(0..only_i.len())];
+            let v = only_j[rng.gen_range(0..only_j.len())];
+            let new_i = swapped(&edges[i], u, v);
+            let new_j = swapped(&edges[j], v, u);
+            if self.edge_list.contains_key(&new_i) || self.edge_list.contains_key(&new_j) {
+                continue;
+            }
+
+            for (idx, new_edge) in [(i, new_i), (j, new_j)] {
+                let weight = self.edge_list[&edges[idx]];
+                self.remove_edge(edges[idx].clone())?;
+                self.add_edge(new_edge.clone(), Some(weight), None)?;
+                // Negli ipergrafi non pesati si conserva anche la molteplicità
+                self.edge_list.insert(new_edge.clone(), weight);
+                edges[idx] = new_edge;
+            }
+            performed += 1;
         }
+
+        Ok(performed)
     }
 
-    /// Checks if the hypergraph is uniform.
+    /// Merges a set of nodes into a single node.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// `true` if the hypergraph has uniform edge sizes, `false` otherwise.
-    pub fn is_uniform(&self) -> bool {
-        self.edges_by_order.len() == 1
-    }
-
-    /// Returns the maximum order of the hypergraph.
+    /// * `nodes`: The nodes to be merged.
+    /// * `into`: The node replacing them. It can be one of `nodes` or a new node.
     ///
     /// # Returns
     ///
-    /// The maximum order of the hypergraph.
-    pub fn max_order(&self) -> usize {
-        return self.max_order;
-    }
-
-    /// Returns the maximum size of the hypergraph.
+    /// * `Ok(())` if the nodes were contracted successfully.
+    /// * `Err(HypergraphError)` if any of `nodes` does not exist in the hypergraph.
     ///
-    /// # Returns
+    /// # Notes
     ///
-    /// The maximum size of the hypergraph, which is the maximum order plus one.
-    pub fn max_size(&self) -> usize {
-        self.max_order + 1
-    }
+    /// Every edge incident to a merged node is rewritten with `into` in place of the merged
+    /// nodes, keeping its weight, weight vector and metadata. Edges that shrink below two nodes
+    /// are dropped, and edges that become identical are joined by summing their weights (or
+    /// multiplicities) and, element by element, their weight vectors.
+    pub fn contract_nodes(&mut self, nodes: Vec<usize>, into: usize) -> Result<(), HypergraphError> {
+        if let Some(&missing) = nodes.iter().find(|node| !self.adj.contains_key(node)) {
+            return Err(HypergraphError::NodeNotFound(missing));
+        }
+        let merged: HashSet<usize> = nodes.into_iter().filter(|&node| node != into).collect();
+
+        let edge_ids: HashSet<usize> = merged
+            .iter()
+            .flat_map(|node| self.adj[node].iter().copied())
+            .collect();
+
+        // Rimuoviamo gli archi incidenti e ne prepariamo la versione contratta
+        let mut rewritten = Vec::with_capacity(edge_ids.len());
+        for edge_id in edge_ids {
+            if let Some(edge) = self.id_to_edge.get(&edge_id).cloned() {
+                let weight = self.edge_list[&edge];
+                let edge_meta = self.attr.get_attr(&format!("{:?}", edge)).ok().cloned();
+                let weight_vec = self.edge_weights_vec.get(&edge).cloned();
+                self.remove_edge(edge.clone())?;
+
+                let mut contracted: Vec<usize> = edge
+                    .into_iter()
+                    .map(|node| if merged.contains(&node) { into } else { node })
+                    .collect();
+                contracted.sort_unstable();
+                contracted.dedup();
+                if contracted.len() >= 2 {
+                    rewritten.push((contracted, weight, edge_meta, weight_vec));
+                }
+            }
+        }
+
+        for node in &merged {
+            self.adj.remove(node);
+            let _ = self.attr.remove_object(&node.to_string());
+        }
+        self.add_node(into);
+
+        for (edge, weight, mut edge_meta, weight_vec) in rewritten {
+            let previous = self.edge_list.get(&edge).copied().unwrap_or(0.0);
+            if let Some(meta) = edge_meta.as_mut() {
+                meta.insert("name".to_string(), format!("{:?}", edge));
+            }
+            self.add_edge(edge.clone(), Some(weight), edge_meta)?;
+            self.edge_list.insert(edge.clone(), previous + weight);
+            if let Some(values) = weight_vec {
+                self.merge_weight_vec(edge, values);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the nodes that are not incident to any edge.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of the isolated nodes of the hypergraph.
+    pub fn isolated_nodes(&self) -> Vec<usize> {
+        let mut isolated: Vec<usize> = self.adj
+            .iter()
+            .filter(|(_, edges)| edges.is_empty())
+            .map(|(&node, _)| node)
+            .collect();
+        isolated.sort_unstable();
+        isolated
+    }
+
+    /// Removes every node that is not incident to any edge.
+    pub fn remove_isolated_nodes(&mut self) {
+        for node in self.isolated_nodes() {
+            let _ = self.remove_node(node, false);
+        }
+    }
+
+    /// Removes multiple nodes from the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes`: The nodes to be removed.
+    /// * `keep_edges`: A boolean indicating whether to keep the edges associated with the nodes.
+    ///
+    /// # Notes
+    ///
+    /// The function is idempotent, i.e., attempting to remove a node that is not in the hypergraph
+    /// does not result in an error.
+    pub fn remove_nodes(
+        &mut self,
+        nodes: Vec<usize>,
+        keep_edges: bool
+    ) {
+        for node in nodes {
+            let _ = self.remove_node(node, keep_edges);
+        }
+    }
+
+    /// Checks if the hypergraph is uniform.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the hypergraph has uniform edge sizes, `false` otherwise.
+    ///
+    /// # Notes
+    ///
+    /// A hypergraph with a single edge is uniform, while the empty hypergraph
+    /// (no edges) is never considered uniform.
+    pub fn is_uniform(&self) -> bool {
+        if self.edge_list.is_empty() {
+            return false;
+        }
+        self.edges_by_order.len() == 1
+    }
+
+    /// Returns the maximum order of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// The maximum order of the hypergraph.
+    pub fn max_order(&self) -> usize {
+        return self.max_order;
+    }
+
+    /// Returns the maximum size of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// The maximum size of the hypergraph, which is the maximum order plus one.
+    pub fn max_size(&self) -> usize {
+        self.max_order + 1
+    }
 
     /// Returns the number of nodes in the hypergraph.
     ///
@@ -584,6 +1191,8 @@ impl HypergraphRust {
     /// * `order`: The order of the edges to be counted. If `None`, counts all edges.
     /// * `size`: The size of the edges to be counted, which is the order plus one. If `None`, counts all edges.
     /// * `up_to`: A boolean indicating whether to count all edges up to the specified order or size. If `false`, counts only edges of the specified order or size.
+    /// * `multiplicity`: If `true`, each edge of an unweighted hypergraph is counted as many times as it was added
+    ///   (see `multiplicity`). Ignored for weighted hypergraphs, where every distinct edge counts once.
     ///
     /// # Returns
     ///
@@ -593,30 +1202,62 @@ impl HypergraphRust {
         order: Option<usize>,
         size: Option<usize>,
         up_to: bool,
-    ) -> Result<usize, String> {
+        multiplicity: bool,
+    ) -> Result<usize, HypergraphError> {
+        let count = |edges: &mut dyn Iterator<Item = &Vec<usize>>| -> usize {
+            if multiplicity && !self.weighted {
+                edges.map(|edge| self.edge_list.get(edge).copied().unwrap_or(0.0) as usize).sum()
+            } else {
+                edges.count()
+            }
+        };
+
         // Controlla se `order` e `size` sono entrambi `None`, restituisci il numero totale di spigoli
         if order.is_none() && size.is_none() {
-            return Ok(self.edge_list.len());
+            return Ok(count(&mut self.edge_list.keys()));
         }
     
         // Se `size` è specificato, lo converte in `order`; altrimenti usa `order`
-        let order = size.map_or(order.unwrap(), |s| s - 1);
+        let order = size.map(|s| s - 1).or(order).unwrap_or(0);
     
         // Se `up_to` è false, conta solo gli spigoli di un ordine specifico
         if !up_to {
             match self.edges_by_order.get(&order) {
-                Some(edges) => Ok(edges.len()),
+                Some(edges) => Ok(count(&mut edges.iter())),
                 None => Ok(0),
             }
         } else {
             // Se `up_to` è true, conta gli spigoli fino all'ordine specificato
-            let mut count = 0;
+            let mut total = 0;
             for i in 0..=order {
                 if let Some(edges) = self.edges_by_order.get(&i) {
-                    count += edges.len();
+                    total += count(&mut edges.iter());
                 }
             }
-            Ok(count)
+            Ok(total)
+        }
+    }
+
+    /// Returns the multiplicity of an edge in the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge`: The edge whose multiplicity should be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` with the number of times the edge was added if the hypergraph is unweighted,
+    ///   or `1.0` if it is weighted (re-adding an edge overwrites its weight).
+    /// * `Err(HypergraphError)` if the edge is not in the hypergraph.
+    pub fn multiplicity(&self, edge: Vec<usize>) -> Result<f64, HypergraphError> {
+        let mut sorted_edge = edge.clone();
+        sorted_edge.sort_unstable();
+        sorted_edge.dedup();
+
+        match self.edge_list.get(&sorted_edge) {
+            Some(&count) if !self.weighted => Ok(count),
+            Some(_) => Ok(1.0),
+            None => Err(HypergraphError::EdgeNotFound(edge)),
         }
     }
 
@@ -633,6 +1274,7 @@ impl HypergraphRust {
     pub fn check_edge(&self, edge: Vec<usize>) -> bool {
         let mut sorted_edge = edge;
         sorted_edge.sort_unstable();
+        sorted_edge.dedup();
         self.edge_list.contains_key(&sorted_edge)
     }
 
@@ -664,6 +1306,8 @@ impl HypergraphRust {
             adj: self.adj.clone(),
             max_order: self.max_order,
             edge_list: self.edge_list.clone(),
+            id_to_edge: self.id_to_edge.clone(),
+            edge_weights_vec: self.edge_weights_vec.clone(),
         };
 
         new_hypergraph
@@ -679,13 +1323,13 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// * `Ok(())` if the metadata was successfully set.
-    /// * `Err(String)` if the object ID is not found in the hypergraph.
-    pub fn set_meta(&mut self, obj_id: usize, metadata: HashMap<String, String>) -> Result<(), String> {
+    /// * `Err(HypergraphError)` if the object ID is not found in the hypergraph.
+    pub fn set_meta(&mut self, obj_id: usize, metadata: HashMap<String, String>) -> Result<(), HypergraphError> {
         if let Some(_obj) = self.attr.get_object_by_id(obj_id) {
             self.attr.set_attributes_by_id(obj_id, metadata);
             Ok(())
         } else {
-            Err(format!("Object ID {} not found in hypergraph", obj_id))
+            Err(HypergraphError::ObjectNotFound(obj_id.to_string()))
         }
     }
 
@@ -699,18 +1343,100 @@ impl HypergraphRust {
         sizes 
     }
 
+    /// Returns the number of node-edge incidences, i.e. the sum of all edge sizes.
+    pub fn num_incidences(&self) -> usize {
+        self.get_sizes().iter().sum()
+    }
+
+    /// Returns descriptive statistics of the edge sizes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the tuple `(mean, std, min, max)` of the edge sizes, where `std` is the
+    /// population standard deviation, or an error if the hypergraph has no edges.
+    pub fn size_stats(&self) -> Result<(f64, f64, usize, usize), HypergraphError> {
+        let sizes = self.get_sizes();
+        let (Some(&min), Some(&max)) = (sizes.iter().min(), sizes.iter().max()) else {
+            return Err(HypergraphError::EmptyHypergraph);
+        };
+
+        let count = sizes.len() as f64;
+        let mean = sizes.iter().sum::<usize>() as f64 / count;
+        let variance = sizes.iter().map(|&size| (size as f64 - mean).powi(2)).sum::<f64>() / count;
+        Ok((mean, variance.sqrt(), min, max))
+    }
+
     /// Returns a distribution of edge sizes in the hypergraph.
     ///
     /// # Returns
     ///
     /// A `HashMap` where each key is an edge size and the corresponding value is the count of edges of that size.
+    ///
+    /// # Notes
+    ///
+    /// The counts are read from `edges_by_order`, which `add_edge` and `remove_edge` keep up to date,
+    /// so the cost depends on the number of distinct sizes rather than on the number of edges.
     pub fn distribution_sizes(&self) -> HashMap<usize, usize> {
-        let mut size_distribution = HashMap::new();
-        for edge in self.edge_list.keys() {
-            let size = edge.len();
-            *size_distribution.entry(size).or_insert(0) += 1;
+        self.edges_by_order
+            .iter()
+            .filter(|(_, edges)| !edges.is_empty())
+            .map(|(&order, edges)| (order + 1, edges.len()))
+            .collect()
+    }
+
+    /// Returns a distribution of edge orders in the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A `HashMap` where each key is an edge order (size - 1) and the corresponding value is the count of edges of that order.
+    pub fn distribution_orders(&self) -> HashMap<usize, usize> {
+        self.edges_by_order
+            .iter()
+            .filter(|(_, edges)| !edges.is_empty())
+            .map(|(&order, edges)| (order, edges.len()))
+            .collect()
+    }
+
+    /// Returns the Shannon entropy of the edge size distribution.
+    ///
+    /// # Returns
+    ///
+    /// The entropy (natural logarithm) of the normalized `distribution_sizes`, `0.0` if there are no edges.
+    pub fn size_entropy(&self) -> f64 {
+        if self.edge_list.is_empty() {
+            return 0.0;
+        }
+
+        let total = self.edge_list.len() as f64;
+        self.distribution_sizes()
+            .values()
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.ln()
+            })
+            .sum()
+    }
+
+    /// Returns the density of the hypergraph.
+    ///
+    /// The density is the number of edges divided by the number of edges that could exist
+    /// with the same node count and edge sizes, i.e. `|E| / sum_k C(n, k)`, where `n` is the
+    /// number of nodes and `k` ranges over the distinct edge sizes observed in the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// The density in `[0, 1]`, `0.0` if there are no edges.
+    pub fn density(&self) -> f64 {
+        if self.edge_list.is_empty() {
+            return 0.0;
         }
-        size_distribution
+
+        let n = self.num_nodes();
+        let possible: f64 = self.distribution_sizes()
+            .keys()
+            .map(|&k| binomial(n, k))
+            .sum();
+        self.edge_list.len() as f64 / possible
     }
 
     /// Returns a sorted vector of all edge orders in the hypergraph.
@@ -723,6 +1449,59 @@ impl HypergraphRust {
         orders
     }
 
+    /// Returns the number of nodes recorded in the metadata store.
+    ///
+    /// # Returns
+    ///
+    /// The count of objects of type `"node"`, which should match `num_nodes`.
+    pub fn num_nodes_meta(&self) -> usize {
+        self.attr.count_by_type("node")
+    }
+
+    /// Returns the number of edges recorded in the metadata store.
+    ///
+    /// # Returns
+    ///
+    /// The count of objects of type `"edge"`, which should match `num_edges`.
+    pub fn num_edges_meta(&self) -> usize {
+        self.attr.count_by_type("edge")
+    }
+
+    /// Sets typed (JSON) attributes for the specified object ID, keeping its string metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj_id`: The ID of the object whose attributes are to be set.
+    /// * `attributes`: The attributes to add or overwrite.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `()`, or an error if the object is not in the hypergraph.
+    pub fn set_attr_json(&mut self, obj_id: usize, attributes: HashMap<String, serde_json::Value>) -> Result<(), HypergraphError> {
+        let obj = self.attr
+            .get_object_by_id(obj_id)
+            .cloned()
+            .ok_or_else(|| HypergraphError::ObjectNotFound(obj_id.to_string()))?;
+        self.attr.set_attr_json(&obj, attributes)
+    }
+
+    /// Returns all the attributes of the specified object ID as JSON values.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj_id`: The ID of the object whose attributes should be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the string metadata (as JSON strings) overlaid with the typed attributes,
+    /// or an error if the object is not in the hypergraph.
+    pub fn get_attr_json(&self, obj_id: usize) -> Result<HashMap<String, serde_json::Value>, HypergraphError> {
+        let obj = self.attr
+            .get_object_by_id(obj_id)
+            .ok_or_else(|| HypergraphError::ObjectNotFound(obj_id.to_string()))?;
+        self.attr.get_attr_json(obj)
+    }
+
     /// Returns the value of a specific attribute for a given object.
     ///
     /// # Arguments
@@ -733,21 +1512,15 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// A `Result` containing a reference to a `String` if the object and attribute exist, or an error message otherwise.
-    pub fn get_attr_meta(&self, obj: usize, attr: String) -> Result<&String, String> {
+    pub fn get_attr_meta(&self, obj: usize, attr: String) -> Result<&String, HypergraphError> {
         if let Some(attributes) = self.attr.get_attributes(obj) {
             if let Some(value) = attributes.get(&attr) {
                 Ok(value)
             } else {
-                Err(format!(
-                    "Attribute '{}' not found for object {}",
-                    attr, obj
-                ))
+                Err(HypergraphError::AttributeNotFound { obj, attr })
             }
         } else {
-            Err(format!(
-                "Object ID {} not found in hypergraph",
-                obj
-            ))
+            Err(HypergraphError::ObjectNotFound(obj.to_string()))
         }
     }
 
@@ -761,14 +1534,18 @@ impl HypergraphRust {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a vector of vectors of `usize` values representing the incident edges of the node
+    /// A `Result` containing a vector of vectors of `usize` values representing the incident edges of the node,
+    /// or an error if `size` is 0.
     pub fn get_incident_edges(
         &self,
         node: usize,
         order: Option<usize>,
         size: Option<usize>,
-    ) -> Result<Vec<Vec<usize>>, String> {
-        let target_order = size.map_or(order, |s| Some(s - 1));
+    ) -> Result<Vec<Vec<usize>>, HypergraphError> {
+        let target_order = size
+            .map(|s| s.checked_sub(1).ok_or_else(|| HypergraphError::InvalidArgument("Size must be at least 1.".to_string())))
+            .transpose()?
+            .or(order);
         
         // Preallocare la capacità basata sulla dimensione dell'adiacenza
         let mut incident_edges = Vec::with_capacity(
@@ -777,14 +1554,9 @@ impl HypergraphRust {
         
         if let Some(edges) = self.adj.get(&node) {
             for &edge_id in edges {
-                if let Some(edge_str) = self.attr.get_object_by_id(edge_id) {
-                    let edge: Vec<usize> = edge_str[1..edge_str.len() - 1]
-                        .split(", ")
-                        .filter_map(|s| s.parse().ok())
-                        .collect();
-                    
-                    if target_order.map_or(true, |order| edge.len() == order + 1) {
-                        incident_edges.push(edge);
+                if let Some(edge) = self.id_to_edge.get(&edge_id) {
+                    if target_order.is_none_or(|order| edge.len() == order + 1) {
+                        incident_edges.push(edge.clone());
                     }
                 }
             }
@@ -794,6 +1566,118 @@ impl HypergraphRust {
         Ok(incident_edges)
     }
 
+    /// Returns the set of metadata IDs of the edges incident to a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The ID of the node.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a reference to the incidence set of `node`, or an error if the node is not in the hypergraph.
+    pub fn get_incidence_set(&self, node: usize) -> Result<&HashSet<usize>, HypergraphError> {
+        self.adj.get(&node).ok_or(HypergraphError::NodeNotFound(node))
+    }
+
+    /// Returns the IDs of the incident edges of a given node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The ID of the node whose incident edges should be retrieved.
+    /// * `order`: An optional parameter specifying the order of the edges to be returned.
+    /// * `size`: An optional parameter specifying the size of the edges to be returned.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the sorted IDs (in the metadata handler) of the incident edges of the node,
+    /// or an error if `size` is 0.
+    pub fn get_incident_edge_ids(
+        &self,
+        node: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        let target_order = size
+            .map(|s| s.checked_sub(1).ok_or_else(|| HypergraphError::InvalidArgument("Size must be at least 1.".to_string())))
+            .transpose()?
+            .or(order);
+
+        let mut edge_ids: Vec<usize> = self.adj
+            .get(&node)
+            .map(|edges| {
+                edges
+                    .iter()
+                    .copied()
+                    .filter(|edge_id| {
+                        self.id_to_edge
+                            .get(edge_id)
+                            .is_some_and(|edge| target_order.is_none_or(|order| edge.len() == order + 1))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        edge_ids.sort_unstable();
+        Ok(edge_ids)
+    }
+
+    /// Returns the edges that contain every node of a given set.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes`: The nodes that must all belong to the returned edges.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector with the matching edges. It is empty if `nodes` is empty or contains a node without incident edges.
+    pub fn edges_containing(&self, nodes: Vec<usize>) -> Vec<Vec<usize>> {
+        let mut incidences = Vec::with_capacity(nodes.len());
+        for node in &nodes {
+            match self.adj.get(node) {
+                Some(edges) => incidences.push(edges),
+                None => return Vec::new(),
+            }
+        }
+
+        // Partiamo dall'insieme di incidenza più piccolo per ridurre i confronti
+        incidences.sort_unstable_by_key(|edges| edges.len());
+        let Some((smallest, rest)) = incidences.split_first() else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<Vec<usize>> = smallest
+            .iter()
+            .filter(|edge_id| rest.iter().all(|edges| edges.contains(edge_id)))
+            .filter_map(|edge_id| self.id_to_edge.get(edge_id).cloned())
+            .collect();
+
+        result.sort_unstable();
+        result
+    }
+
+    /// Returns the weights of the edges incident to a given node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The ID of the node whose incident edge weights should be retrieved.
+    /// * `order`: An optional parameter specifying the order of the edges to be considered.
+    /// * `size`: An optional parameter specifying the size of the edges to be considered.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the weights, in the same order as the edges returned by `get_incident_edges`.
+    pub fn get_incident_weights(
+        &self,
+        node: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Vec<f64>, HypergraphError> {
+        self.get_incident_edges(node, order, size)?
+            .into_iter()
+            .map(|edge| self.get_weight(edge))
+            .collect()
+    }
+
     /// Returns the weight of a specific edge in the hypergraph.
     ///
     /// # Arguments
@@ -803,16 +1687,14 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// A `Result` containing the weight of the edge, or an error message if the edge is not in the hypergraph.
-    pub fn get_weight(&self, edge: Vec<usize>) -> Result<f64, String> {
+    pub fn get_weight(&self, edge: Vec<usize>) -> Result<f64, HypergraphError> {
         let mut sorted_edge = edge.clone();
         sorted_edge.sort_unstable();
+        sorted_edge.dedup();
     
         match self.edge_list.get(&sorted_edge) {
             Some(&weight) => Ok(weight),
-            None => Err(format!(
-                "Edge {:?} not in hypergraph.",
-                edge
-            )),
+            None => Err(HypergraphError::EdgeNotFound(edge)),
         }
     }
 
@@ -826,117 +1708,375 @@ impl HypergraphRust {
     /// # Returns
     ///
     /// A `Result` containing `()`, or an error message if the edge is not in the hypergraph.
-    pub fn set_weight(&mut self, edge: Vec<usize>, weight: f64) -> Result<(), String> {
+    pub fn set_weight(&mut self, edge: Vec<usize>, weight: f64) -> Result<(), HypergraphError> {
         let mut sorted_edge = edge.clone();
         sorted_edge.sort_unstable();
+        sorted_edge.dedup();
 
         if self.edge_list.contains_key(&sorted_edge) {
             self.edge_list.insert(sorted_edge, weight);
             Ok(())
         } else {
-            Err(format!("Edge {:?} not in hypergraph.", edge))
+            Err(HypergraphError::EdgeNotFound(edge))
         }
     }
 
-    /// Returns the neighbors of a given node in the hypergraph.
+    /// Attaches a vector of numeric attributes to an edge, alongside its scalar weight.
     ///
     /// # Arguments
     ///
-    /// * `node`: The ID of the node whose neighbors should be retrieved.
-    /// * `order`: An optional parameter specifying the order of the edges to be returned.
-    /// * `size`: An optional parameter specifying the size of the edges to be returned.
+    /// * `edge`: The edge whose weight vector should be set.
+    /// * `weights`: The vector of values. It must have the same length as the vectors already stored.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a vector of `usize` values representing the neighbors of the node
-    pub fn get_neighbors(
-        &self,
-        node: usize,
-        order: Option<usize>,
-        size: Option<usize>,
-    ) -> Result<Vec<usize>, String> {
-        // Usa una FxHashSet per performance migliori
-        let mut neighbors = rustc_hash::FxHashSet::default();
-        
-        if let Some(edges) = self.adj.get(&node) {
-            for &edge_id in edges {
-                if let Some(edge_str) = self.attr.get_object_by_id(edge_id) {
-                    let edge: Vec<usize> = edge_str[1..edge_str.len() - 1]
-                        .split(", ")
-                        .filter_map(|s| s.parse().ok())
-                        .collect();
-                    
-                    if size.map_or(true, |s| edge.len() == s) 
-                        && order.map_or(true, |o| edge.len() == o + 1) {
-                        neighbors.extend(edge.iter().filter(|&&n| n != node));
-                    }
-                }
+    /// A `Result` containing `()`, or an error if the edge is not in the hypergraph or the length does not match.
+    pub fn set_weight_vec(&mut self, edge: Vec<usize>, weights: Vec<f64>) -> Result<(), HypergraphError> {
+        let mut sorted_edge = edge.clone();
+        sorted_edge.sort_unstable();
+        sorted_edge.dedup();
+
+        if !self.edge_list.contains_key(&sorted_edge) {
+            return Err(HypergraphError::EdgeNotFound(edge));
+        }
+
+        // Tutti i vettori devono avere la stessa lunghezza (salvo quello che stiamo sostituendo)
+        let expected = self.edge_weights_vec
+            .iter()
+            .find(|(other, _)| **other != sorted_edge)
+            .map(|(_, values)| values.len());
+        if let Some(expected) = expected {
+            if expected != weights.len() {
+                return Err(HypergraphError::InvalidArgument(format!(
+                    "Weight vectors must all have length {}, got {}.", expected, weights.len()
+                )));
             }
         }
-        
-        Ok(neighbors.into_iter().collect())
+
+        self.edge_weights_vec.insert(sorted_edge, weights);
+        Ok(())
     }
 
-    /// Returns the weights of all edges in the hypergraph.
+    /// Returns the vector of numeric attributes attached to an edge.
     ///
     /// # Arguments
     ///
-    /// * `order`: An optional parameter specifying the order of the edges to be returned.
-    /// * `size`: An optional parameter specifying the size of the edges to be returned.
-    /// * `up_to`: A boolean value indicating whether to return weights up to the specified order or size.
+    /// * `edge`: The edge whose weight vector should be retrieved.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a vector of `f64` values representing the weights of the edges, or an error message if the edge is not in the hypergraph.
-    pub fn get_weights(
-        &self,
-        order: Option<usize>,
-        size: Option<usize>,
-        up_to: bool,
-    ) -> Result<Vec<f64>, String> {
-        // Controllo se entrambi `order` e `size` sono specificati
-        if order.is_some() && size.is_some() {
-            return Err("Order and size cannot be both specified.".to_string());
+    /// A `Result` containing the vector, or `None` if none was set, or an error if the edge is not in the hypergraph.
+    pub fn get_weight_vec(&self, edge: Vec<usize>) -> Result<Option<&Vec<f64>>, HypergraphError> {
+        let mut sorted_edge = edge.clone();
+        sorted_edge.sort_unstable();
+        sorted_edge.dedup();
+
+        if !self.edge_list.contains_key(&sorted_edge) {
+            return Err(HypergraphError::EdgeNotFound(edge));
         }
+        Ok(self.edge_weights_vec.get(&sorted_edge))
+    }
 
-        let mut weights: Vec<f64> = Vec::new();
-
-        // Caso 1: né `order` né `size` sono specificati -> restituisce tutti i pesi
-        if order.is_none() && size.is_none() {
-            weights.extend(self.edge_list.values().cloned());
-        } else {
-            // Determina l'ordine target: usa `size - 1` se `size` è specificato, altrimenti usa `order`
-            let target_order = size.map(|s| s - 1).or(order);
-
-            // `up_to` == true, quindi restituisce tutti i pesi degli spigoli fino a `target_order`
-            if up_to {
-                // Itera sugli ordini da 1 fino a `target_order`
-                for current_order in 0..=target_order.unwrap_or(0) {
-                    if let Some(order_edges) = self.edges_by_order.get(&current_order) {
-                        for edge in order_edges {
-                            if let Some(&weight) = self.edge_list.get(edge) {
-                                weights.push(weight);
-                            }
-                        }
-                    }
+    /// Stores `values` as the weight vector of `edge`, summing them element by element into the
+    /// vector already attached to it, if any.
+    fn merge_weight_vec(&mut self, edge: Vec<usize>, values: Vec<f64>) {
+        match self.edge_weights_vec.entry(edge) {
+            std::collections::hash_map::Entry::Occupied(mut current) => {
+                for (total, value) in current.get_mut().iter_mut().zip(values) {
+                    *total += value;
                 }
-            } else {
-                // `up_to` == false, restituisce solo i pesi degli spigoli di esattamente `target_order`
-                if let Some(current_order) = target_order {
-                    if let Some(order_edges) = self.edges_by_order.get(&current_order) {
-                        for edge in order_edges {
-                            if let Some(&weight) = self.edge_list.get(edge) {
-                                weights.push(weight);
-                            }
-                        }
+            }
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(values);
+            }
+        }
+    }
+
+    /// Returns the sum of the weights of all edges in the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// The total edge weight. For unweighted hypergraphs this is the number of edges counted with multiplicity.
+    pub fn total_weight(&self) -> f64 {
+        self.edge_list.values().sum()
+    }
+
+    /// Rescales the edge weights so that they sum to one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `()`, or an error message if the hypergraph is unweighted or its total weight is zero.
+    pub fn normalize_weights(&mut self) -> Result<(), HypergraphError> {
+        if !self.weighted {
+            return Err(HypergraphError::InvalidArgument("Cannot normalize the weights of an unweighted hypergraph.".to_string()));
+        }
+
+        let total = self.total_weight();
+        if total == 0.0 {
+            return Err(HypergraphError::InvalidArgument("Cannot normalize weights with a total weight of zero.".to_string()));
+        }
+
+        for weight in self.edge_list.values_mut() {
+            *weight /= total;
+        }
+        Ok(())
+    }
+
+    /// Collapses the copies of each edge into a single weighted edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate`: How the weights of coincident edges are combined: `"sum"`, `"max"` or `"mean"`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `()`, or an error if `aggregate` is not a supported mode.
+    ///
+    /// # Notes
+    ///
+    /// In an unweighted hypergraph the copies of an edge are tracked as its multiplicity, each copy
+    /// with weight `1.0`: the hypergraph becomes weighted and each edge gets the multiplicity
+    /// (`"sum"`) or `1.0` (`"max"`, `"mean"`) as weight. A weighted hypergraph already stores every
+    /// node set once, with the weight of the last insertion, so it is left unchanged. Edge
+    /// metadata is kept in both cases.
+    pub fn collapse_edges(&mut self, aggregate: &str) -> Result<(), HypergraphError> {
+        let sum = match aggregate {
+            "sum" => true,
+            "max" | "mean" => false,
+            _ => return Err(HypergraphError::InvalidArgument(format!(
+                "Unknown aggregate '{}'. Expected 'sum', 'max' or 'mean'.", aggregate
+            ))),
+        };
+
+        if self.weighted {
+            return Ok(());
+        }
+
+        if !sum {
+            for weight in self.edge_list.values_mut() {
+                *weight = 1.0;
+            }
+        }
+        self.weighted = true;
+        Ok(())
+    }
+
+    /// Draws `k` distinct edges with probability proportional to their weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: The number of edges to draw. If larger than the number of edges, every edge is returned.
+    /// * `seed`: An optional seed for the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the sampled edges, in the order in which they were drawn. Unweighted hypergraphs are sampled uniformly.
+    pub fn sample_edges(&self, k: usize, seed: Option<u64>) -> Vec<Vec<usize>> {
+        let mut rng = rng_from_seed(seed);
+
+        // Ordiniamo gli archi per rendere il campionamento riproducibile con lo stesso seed
+        let mut edges: Vec<(&Vec<usize>, f64)> = self.edge_list
+            .iter()
+            .map(|(edge, &weight)| (edge, if self.weighted { weight } else { 1.0 }))
+            .collect();
+        edges.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        // Efraimidis-Spirakis: ogni arco riceve la chiave u^(1/w) e si tengono le k chiavi maggiori
+        let mut keyed: Vec<(f64, &Vec<usize>)> = edges
+            .into_iter()
+            .map(|(edge, weight)| {
+                let u: f64 = rng.gen();
+                let key = if weight > 0.0 { u.powf(1.0 / weight) } else { 0.0 };
+                (key, edge)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        keyed.into_iter().take(k).map(|(_, edge)| edge.clone()).collect()
+    }
+
+    /// Builds a bootstrap replicate of the hypergraph by resampling its edges with replacement.
+    ///
+    /// As many edges as the hypergraph contains are drawn, each with probability proportional to its
+    /// multiplicity (unweighted hypergraphs) or uniformly among the distinct edges (weighted ones).
+    /// An edge drawn `k` times gets multiplicity `k`, or `k` times its original weight. All the nodes
+    /// are kept, so measures on the replicate are comparable with the original ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed`: An optional seed for the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// A new `HypergraphRust` with the same nodes and weighting as the original.
+    pub fn bootstrap(&self, seed: Option<u64>) -> HypergraphRust {
+        let mut rng = rng_from_seed(seed);
+
+        // Ordiniamo gli archi per rendere il campionamento riproducibile con lo stesso seed
+        let mut edges: Vec<(&Vec<usize>, f64)> = self.edge_list.iter().map(|(edge, &weight)| (edge, weight)).collect();
+        edges.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let mut replicate = HypergraphRust::with_capacity(self.num_nodes(), edges.len(), self.weighted);
+        replicate.add_nodes(self.get_nodes_without_metadata());
+        if edges.is_empty() {
+            return replicate;
+        }
+
+        let counts: Vec<f64> = edges.iter().map(|&(_, weight)| if self.weighted { 1.0 } else { weight }).collect();
+        let total = counts.iter().sum::<f64>() as usize;
+        let Ok(distribution) = rand::distributions::WeightedIndex::new(&counts) else {
+            return replicate;
+        };
+
+        let mut draws = vec![0usize; edges.len()];
+        for _ in 0..total {
+            draws[rng.sample(&distribution)] += 1;
+        }
+
+        for ((edge, weight), count) in edges.into_iter().zip(draws) {
+            if count == 0 {
+                continue;
+            }
+            let _ = replicate.add_edge(edge.clone(), self.weighted.then_some(weight * count as f64), None);
+            // Negli ipergrafi non pesati il numero di estrazioni diventa la molteplicità
+            if !self.weighted {
+                replicate.edge_list.insert(edge.clone(), count as f64);
+            }
+        }
+
+        replicate
+    }
+
+    /// Returns the neighbors of a given node in the hypergraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The ID of the node whose neighbors should be retrieved.
+    /// * `order`: An optional parameter specifying the order of the edges to be returned.
+    /// * `size`: An optional parameter specifying the size of the edges to be returned.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a sorted vector of `usize` values representing the neighbors of the node
+    pub fn get_neighbors(
+        &self,
+        node: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> Result<Vec<usize>, HypergraphError> {
+        // Usa una FxHashSet per performance migliori
+        let mut neighbors = rustc_hash::FxHashSet::default();
+        
+        if let Some(edges) = self.adj.get(&node) {
+            for &edge_id in edges {
+                if let Some(edge) = self.id_to_edge.get(&edge_id) {
+                    if size.map_or(true, |s| edge.len() == s) 
+                        && order.map_or(true, |o| edge.len() == o + 1) {
+                        neighbors.extend(edge.iter().filter(|&&n| n != node));
                     }
                 }
             }
         }
+        
+        let mut neighbors: Vec<usize> = neighbors.into_iter().collect();
+        neighbors.sort_unstable();
+        Ok(neighbors)
+    }
+
+    /// Returns the nodes that share at least one edge with both `u` and `v`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The ID of the first node.
+    /// * `v`: The ID of the second node.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with a sorted vector of the common neighbors; `u` and `v` themselves are never included.
+    /// A `NodeNotFound` error is returned if either node is not in the hypergraph.
+    pub fn common_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, HypergraphError> {
+        for node in [u, v] {
+            if !self.check_node(node) {
+                return Err(HypergraphError::NodeNotFound(node));
+            }
+        }
+
+        let neighbors_u: rustc_hash::FxHashSet<usize> = self.get_neighbors(u, None, None)?
+            .into_iter()
+            .collect();
+
+        let mut common: Vec<usize> = self.get_neighbors(v, None, None)?
+            .into_iter()
+            .filter(|n| *n != u && neighbors_u.contains(n))
+            .collect();
+
+        common.sort_unstable();
+        Ok(common)
+    }
+
+    /// Returns how many edges contain both `u` and `v`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The ID of the first node.
+    /// * `v`: The ID of the second node.
+    /// * `weighted`: If `true`, the weights of the shared edges are summed instead of counted.
+    ///
+    /// # Returns
+    ///
+    /// The number (or total weight) of the edges shared by the two nodes, `0.0` if either node has no edges.
+    pub fn co_occurrence(&self, u: usize, v: usize, weighted: bool) -> f64 {
+        let (Some(edges_u), Some(edges_v)) = (self.adj.get(&u), self.adj.get(&v)) else {
+            return 0.0;
+        };
+
+        // Scorriamo l'insieme di incidenza più piccolo
+        let (small, large) = if edges_u.len() <= edges_v.len() { (edges_u, edges_v) } else { (edges_v, edges_u) };
+
+        small
+            .iter()
+            .filter(|edge_id| large.contains(edge_id))
+            .filter_map(|edge_id| self.id_to_edge.get(edge_id))
+            .map(|edge| if weighted { self.edge_list.get(edge).copied().unwrap_or(0.0) } else { 1.0 })
+            .sum()
+    }
+
+    /// Returns the weights of all edges in the hypergraph.
+    ///
+    /// The weights are listed in the same order as the edges returned by `get_edges`
+    /// with the same filters, so the two vectors can be zipped safely.
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: An optional parameter specifying the order of the edges to be returned.
+    /// * `size`: An optional parameter specifying the size of the edges to be returned.
+    /// * `up_to`: A boolean value indicating whether to return weights up to the specified order or size.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `f64` values representing the weights of the edges, or an error message if the edge is not in the hypergraph.
+    pub fn get_weights(
+        &self,
+        order: Option<usize>,
+        size: Option<usize>,
+        up_to: bool,
+    ) -> Result<Vec<f64>, HypergraphError> {
+        // Controllo se entrambi `order` e `size` sono specificati
+        if order.is_some() && size.is_some() {
+            return Err(HypergraphError::InvalidArgument("Order and size cannot be both specified.".to_string()));
+        }
 
-        Ok(weights)
+        // Si scorrono gli stessi spigoli di `get_edges`, così l'ordine coincide per costruzione
+        Ok(self.get_edges(false, order, size, up_to)?
+            .into_iter()
+            .map(|edge| self.edge_list[edge])
+            .collect())
     }
 
+    /// Checks whether every node can reach every other node through the edges.
+    ///
+    /// Isolated nodes (for instance those left behind by `remove_edge`) are separate components;
+    /// `remove_isolated_nodes` drops them.
     pub fn is_connected_rust(&self) -> bool {
         if self.num_nodes() == 0 {
             return true;
@@ -956,13 +2096,8 @@ impl HypergraphRust {
         while let Some(node) = to_visit.pop() {
             if let Some(edges) = self.adj.get(&node) {
                 for &edge_id in edges {
-                    if let Some(edge_str) = self.attr.get_object_by_id(edge_id) {
-                        let edge: Vec<usize> = edge_str[1..edge_str.len() - 1]
-                            .split(", ")
-                            .filter_map(|s| s.parse().ok())
-                            .collect();
-                        
-                        for &neighbor in &edge {
+                    if let Some(edge) = self.id_to_edge.get(&edge_id) {
+                        for &neighbor in edge {
                             if visited.insert(neighbor) {
                                 to_visit.push(neighbor);
                             }
@@ -975,6 +2110,45 @@ impl HypergraphRust {
         visited.len() == self.num_nodes()
     }
 
+    /// Computes the shortest-hop distance from a node to every node reachable from it.
+    ///
+    /// Two nodes are one hop apart when they share at least one edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: The ID of the node the search starts from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `HashMap` from each reachable node (including `source`, at distance 0)
+    /// to its distance, or an error if `source` is not in the hypergraph.
+    pub fn shortest_path_lengths(&self, source: usize) -> Result<HashMap<usize, usize>, HypergraphError> {
+        if !self.adj.contains_key(&source) {
+            return Err(HypergraphError::NodeNotFound(source));
+        }
+
+        let mut distances = HashMap::new();
+        let mut frontier = std::collections::VecDeque::new();
+        distances.insert(source, 0);
+        frontier.push_back(source);
+
+        while let Some(node) = frontier.pop_front() {
+            let next = distances[&node] + 1;
+            for edge_id in &self.adj[&node] {
+                if let Some(edge) = self.id_to_edge.get(edge_id) {
+                    for &neighbor in edge {
+                        if let std::collections::hash_map::Entry::Vacant(entry) = distances.entry(neighbor) {
+                            entry.insert(next);
+                            frontier.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+
     /// Returns a subgraph of the hypergraph with the specified nodes.
     ///
     /// # Arguments
@@ -983,8 +2157,10 @@ impl HypergraphRust {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `HypergraphRust` object representing the subgraph, or an error message if the nodes are not in the hypergraph.
-    pub fn subhypergraph(&self, nodes: Vec<usize>) -> HypergraphRust {
+    /// A `Result` containing a `HypergraphRust` object with the requested nodes that are in the hypergraph
+    /// (isolated ones included) and their metadata, and the edges made only of them with their weights (or
+    /// multiplicities), weight vectors and metadata. Requested nodes that are not in the hypergraph are ignored.
+    pub fn subhypergraph(&self, nodes: Vec<usize>) -> Result<HypergraphRust, HypergraphError> {
         // Creare un HashSet per lookup O(1)
         let node_set: rustc_hash::FxHashSet<_> = nodes.iter().copied().collect();
         
@@ -1000,14 +2176,16 @@ impl HypergraphRust {
             adj: rustc_hash::FxHashMap::with_capacity_and_hasher(estimated_nodes, Default::default()),
             max_order: 0,
             edge_list: rustc_hash::FxHashMap::with_capacity_and_hasher(estimated_edges, Default::default()),
+            id_to_edge: rustc_hash::FxHashMap::with_capacity_and_hasher(estimated_edges, Default::default()),
+            edge_weights_vec: rustc_hash::FxHashMap::default(),
         };
 
-        // Copiare i nodi e i loro metadati
+        // Copiare i nodi e i loro metadati (cercati per nodo, non per ID dell'oggetto)
         for &node in &nodes {
-            if let Some(_node_attrs) = self.attr.get_attributes(node) {
+            if self.check_node(node) {
                 subgraph.add_node(node);
-                if let Some(node_meta) = self.get_meta(node) {
-                    let _ = subgraph.set_meta(node, node_meta.clone());
+                if let Ok(node_meta) = self.attr.get_attr(&node.to_string()) {
+                    subgraph.attr.set_attr(&node.to_string(), node_meta.clone())?;
                 }
             }
         }
@@ -1017,18 +2195,12 @@ impl HypergraphRust {
             // Verifica se tutti i nodi dell'arco sono nel sottoinsieme
             if edge.iter().all(|node| node_set.contains(node)) {
                 let edge_str = format!("{:?}", edge);
-                if let Ok(edge_meta) = self.attr.get_attr(&edge_str) {
-                    subgraph.add_edge(
-                        edge.clone(),
-                        Some(*weight),
-                        Some(edge_meta.clone())
-                    ).unwrap_or_default();
-                } else {
-                    subgraph.add_edge(
-                        edge.clone(),
-                        Some(*weight),
-                        None
-                    ).unwrap_or_default();
+                let edge_meta = self.attr.get_attr(&edge_str).ok().cloned();
+                subgraph.add_edge(edge.clone(), None, edge_meta)?;
+                // Copiamo direttamente peso o molteplicità dell'arco originale
+                subgraph.edge_list.insert(edge.clone(), *weight);
+                if let Some(values) = self.edge_weights_vec.get(edge) {
+                    subgraph.edge_weights_vec.insert(edge.clone(), values.clone());
                 }
             }
         }
@@ -1038,14 +2210,497 @@ impl HypergraphRust {
             subgraph.max_order = max;
         }
 
-        subgraph
+        Ok(subgraph)
     }
 
-    pub fn get_mapping(&self) -> Result<LabelEncoder, String> {
+    /// Returns the subgraph made of the edges with the given metadata IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge_ids`: The IDs assigned to the edges by the metadata handler.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `HypergraphRust` object with the selected edges, their weights
+    /// (or multiplicities) and metadata, and exactly the nodes appearing in them, or an error if
+    /// an ID does not identify an edge of the hypergraph.
+    pub fn subhypergraph_by_edges(&self, edge_ids: Vec<usize>) -> Result<HypergraphRust, HypergraphError> {
+        let mut subgraph = HypergraphRust::empty(self.weighted);
+
+        for edge_id in edge_ids {
+            let edge = self.id_to_edge
+                .get(&edge_id)
+                .ok_or_else(|| HypergraphError::ObjectNotFound(edge_id.to_string()))?;
+            if subgraph.edge_list.contains_key(edge) {
+                continue;
+            }
+
+            let edge_meta = self.attr.get_attr(&format!("{:?}", edge)).ok().cloned();
+            subgraph.add_edge(edge.clone(), None, edge_meta)?;
+            // Copiamo direttamente peso o molteplicità dell'arco originale
+            subgraph.edge_list.insert(edge.clone(), self.edge_list[edge]);
+            if let Some(values) = self.edge_weights_vec.get(edge) {
+                subgraph.edge_weights_vec.insert(edge.clone(), values.clone());
+            }
+
+            for node in edge {
+                if let Ok(node_meta) = self.attr.get_attr(&node.to_string()) {
+                    subgraph.attr.set_attr(&node.to_string(), node_meta.clone())?;
+                }
+            }
+        }
+
+        Ok(subgraph)
+    }
+
+    /// Returns a subgraph of the hypergraph containing only the edges of the given orders or sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `orders`: The orders of the edges to be kept.
+    /// * `sizes`: The sizes of the edges to be kept.
+    /// * `keep_nodes`: If `true`, all nodes of the hypergraph are kept, even if isolated in the subgraph.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `HypergraphRust` object with the selected edges, their weights and metadata,
+    /// or an error message if `orders` and `sizes` are both (or neither) specified.
+    pub fn subhypergraph_by_orders(
+        &self,
+        orders: Option<Vec<usize>>,
+        sizes: Option<Vec<usize>>,
+        keep_nodes: bool,
+    ) -> Result<HypergraphRust, HypergraphError> {
+        let orders: Vec<usize> = match (orders, sizes) {
+            (Some(_), Some(_)) => return Err(HypergraphError::InvalidArgument("Order and size cannot both be specified.".to_string())),
+            (None, None) => return Err(HypergraphError::InvalidArgument("At least one between orders and sizes should be specified.".to_string())),
+            (Some(orders), None) => orders,
+            (None, Some(sizes)) => sizes.into_iter().filter(|&s| s > 0).map(|s| s - 1).collect(),
+        };
+
+        let mut subgraph = HypergraphRust::empty(self.weighted);
+
+        if keep_nodes {
+            for node in self.get_nodes_without_metadata() {
+                subgraph.add_node(node);
+            }
+        }
+
+        for order in orders {
+            if let Some(order_edges) = self.edges_by_order.get(&order) {
+                for edge in order_edges {
+                    let weight = self.edge_list.get(edge).copied();
+                    let edge_str = format!("{:?}", edge);
+                    let edge_meta = self.attr.get_attr(&edge_str).ok().cloned();
+                    subgraph.add_edge(edge.clone(), weight, edge_meta)?;
+                    if let Some(values) = self.edge_weights_vec.get(edge) {
+                        subgraph.edge_weights_vec.insert(edge.clone(), values.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(subgraph)
+    }
+
+    /// Returns the weighted two-section (clique projection) of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A `HashMap` from each pair `(u, v)` with `u < v` of nodes sharing at least one edge to the sum of
+    /// the weights (multiplicities, if unweighted) of the edges containing both.
+    pub fn two_section(&self) -> HashMap<(usize, usize), f64> {
+        let mut pairs = HashMap::new();
+        for (edge, &weight) in &self.edge_list {
+            for i in 0..edge.len() {
+                for j in (i + 1)..edge.len() {
+                    *pairs.entry((edge[i], edge[j])).or_insert(0.0) += weight;
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Returns the dense two-section adjacency matrix of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// The nodes in ascending order and the symmetric matrix whose entry `(i, j)` is the `two_section`
+    /// weight of the pair of nodes `i` and `j`, or 0 if they share no edge. This is the adjacency used
+    /// by the CEC centralities.
+    pub fn adjacency_matrix(&self) -> (Vec<usize>, Vec<Vec<f64>>) {
+        let mut nodes = self.get_nodes_without_metadata();
+        nodes.sort_unstable();
+        let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+
+        let mut matrix = vec![vec![0.0; nodes.len()]; nodes.len()];
+        for ((u, v), weight) in self.two_section() {
+            let (i, j) = (index[&u], index[&v]);
+            matrix[i][j] = weight;
+            matrix[j][i] = weight;
+        }
+
+        (nodes, matrix)
+    }
+
+    /// Returns the clique expansion of the hypergraph as a new hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A weighted `HypergraphRust` with one size-2 edge for each pair in `two_section`, weighted by the
+    /// summed weight of the pair. Isolated nodes are kept.
+    pub fn clique_expansion(&self) -> HypergraphRust {
+        let mut expansion = HypergraphRust::empty(true);
+        for ((u, v), weight) in self.two_section() {
+            let _ = expansion.add_edge(vec![u, v], Some(weight), None);
+        }
+        expansion.add_nodes(self.isolated_nodes());
+        expansion
+    }
+
+    /// Returns the star expansion of the hypergraph.
+    ///
+    /// Each edge becomes a new "edge-node" linked by a size-2 edge to every node it contains.
+    /// Edges are numbered in lexicographic order and the `i`-th one gets the ID `max_node + 1 + i`,
+    /// where `max_node` is the largest node ID of the hypergraph, so edge-node IDs never collide
+    /// with original nodes. The metadata of every edge-node stores the original edge under `"edge"`.
+    ///
+    /// # Returns
+    ///
+    /// A weighted bipartite `HypergraphRust` where the links of each edge-node carry the weight of its
+    /// edge, or its multiplicity if the hypergraph is unweighted, as in `clique_expansion`. Isolated
+    /// nodes are kept.
+    pub fn star_expansion(&self) -> HypergraphRust {
+        let mut expansion = HypergraphRust::empty(true);
+        let offset = self.adj.keys().max().map_or(0, |&max_node| max_node + 1);
+
+        let mut edges: Vec<(&Vec<usize>, f64)> = self.edge_list.iter().map(|(edge, &weight)| (edge, weight)).collect();
+        edges.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        for (i, (edge, weight)) in edges.into_iter().enumerate() {
+            let edge_node = offset + i;
+            for &node in edge {
+                let _ = expansion.add_edge(vec![node, edge_node], Some(weight), None);
+            }
+            let mut edge_node_meta = HashMap::new();
+            edge_node_meta.insert("edge".to_string(), format!("{:?}", edge));
+            let _ = expansion.attr.set_attr(&edge_node.to_string(), edge_node_meta);
+        }
+        expansion.add_nodes(self.isolated_nodes());
+        expansion
+    }
+
+    /// Returns the star of a node, i.e. the subgraph made of all edges incident to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `node`: The ID of the central node.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with a `HypergraphRust` holding the incident edges, their weights, weight vectors and metadata, and the nodes
+    /// they contain. If the node is isolated the star contains only the node; if it is not in the hypergraph a
+    /// `NodeNotFound` error is returned.
+    pub fn star(&self, node: usize) -> Result<HypergraphRust, HypergraphError> {
+        if !self.check_node(node) {
+            return Err(HypergraphError::NodeNotFound(node));
+        }
+
+        let mut star = HypergraphRust::empty(self.weighted);
+        star.add_node(node);
+
+        for edge in self.get_incident_edges(node, None, None)? {
+            let weight = self.edge_list.get(&edge).copied();
+            let edge_str = format!("{:?}", edge);
+            let edge_meta = self.attr.get_attr(&edge_str).ok().cloned();
+            star.add_edge(edge.clone(), weight, edge_meta)?;
+            if let Some(values) = self.edge_weights_vec.get(&edge) {
+                star.edge_weights_vec.insert(edge, values.clone());
+            }
+        }
+
+        Ok(star)
+    }
+
+    /// Returns the downward (simplicial) closure of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with a new `HypergraphRust` where, for every edge, all of its subsets with at least two nodes
+    /// are also edges. Existing edges keep their weight and metadata; the added faces get the default weight
+    /// and metadata.
+    pub fn simplicial_closure(&self) -> Result<HypergraphRust, HypergraphError> {
+        let mut closure = self.copy();
+
+        // Visitiamo le facce scendendo di un nodo alla volta, così ogni sottoinsieme viene generato una sola volta
+        let mut seen: HashSet<Vec<usize>> = self.edge_list.keys().cloned().collect();
+        let mut stack: Vec<Vec<usize>> = self.edge_list.keys().filter(|edge| edge.len() > 2).cloned().collect();
+        stack.sort_unstable();
+
+        while let Some(edge) = stack.pop() {
+            for i in 0..edge.len() {
+                let mut face = edge.clone();
+                face.remove(i);
+                if seen.insert(face.clone()) {
+                    closure.add_edge(face.clone(), None, None)?;
+                    if face.len() > 2 {
+                        stack.push(face);
+                    }
+                }
+            }
+        }
+
+        Ok(closure)
+    }
+
+    /// Checks whether the hypergraph is closed under taking subsets of at least two nodes.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every subset with at least two nodes of every edge is itself an edge, `false` otherwise.
+    pub fn is_simplicial(&self) -> bool {
+        // Basta controllare le facce immediate: per induzione sono presenti anche quelle più piccole
+        self.edge_list.keys().filter(|edge| edge.len() > 2).all(|edge| {
+            (0..edge.len()).all(|i| {
+                let mut face = edge.clone();
+                face.remove(i);
+                self.edge_list.contains_key(&face)
+            })
+        })
+    }
+
+    /// Returns the maximal edges (toplexes) of the hypergraph.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector with the edges that are not contained in any larger edge.
+    pub fn maximal_edges(&self) -> Vec<Vec<usize>> {
+        // Raggruppiamo gli archi per dimensione, così ogni arco viene confrontato solo con quelli più grandi
+        let mut by_size: BTreeMap<usize, Vec<&Vec<usize>>> = BTreeMap::new();
+        for edge in self.edge_list.keys() {
+            by_size.entry(edge.len()).or_default().push(edge);
+        }
+
+        let mut maximal = Vec::new();
+        for (&size, edges) in &by_size {
+            for edge in edges {
+                let contained = by_size
+                    .range(size + 1..)
+                    .flat_map(|(_, larger)| larger.iter())
+                    .any(|larger| is_sorted_subset(edge, larger));
+                if !contained {
+                    maximal.push((*edge).clone());
+                }
+            }
+        }
+
+        maximal.sort_unstable();
+        maximal
+    }
+
+    /// Returns a Weisfeiler-Lehman hash of the hypergraph structure.
+    ///
+    /// Colors are refined on the node-edge incidence structure: at each iteration every edge
+    /// takes the hash of its color and of the multiset of its nodes' colors, then every node takes
+    /// the hash of its color and of the multiset of its incident edges' colors. The hash combines
+    /// the color histograms of all iterations, so it does not depend on node labels.
+    ///
+    /// # Arguments
+    ///
+    /// * `iterations`: The number of refinement steps.
+    ///
+    /// # Returns
+    ///
+    /// A hexadecimal hash string. Isomorphic hypergraphs get equal hashes; as with any WL test,
+    /// equal hashes do not guarantee isomorphism. Weights and metadata are ignored. The hash is
+    /// stable across runs and builds of the same library version on the same platform, so it can
+    /// be stored and compared later.
+    pub fn wl_hash(&self, iterations: usize) -> String {
+        let mut node_colors: HashMap<usize, u64> = self.adj.keys().map(|&node| (node, 0)).collect();
+        let mut edge_colors: HashMap<&Vec<usize>, u64> = self.edge_list
+            .keys()
+            .map(|edge| (edge, hash_of(&edge.len())))
+            .collect();
+
+        let mut history = vec![color_histogram(&node_colors), color_histogram(&edge_colors)];
+        for _ in 0..iterations {
+            edge_colors = edge_colors
+                .iter()
+                .map(|(&edge, &color)| {
+                    let mut members: Vec<u64> = edge.iter().map(|node| node_colors[node]).collect();
+                    members.sort_unstable();
+                    (edge, hash_of(&(color, members)))
+                })
+                .collect();
+
+            node_colors = node_colors
+                .iter()
+                .map(|(&node, &color)| {
+                    let mut incident: Vec<u64> = self.adj[&node]
+                        .iter()
+                        .filter_map(|edge_id| self.id_to_edge.get(edge_id))
+                        .map(|edge| edge_colors[edge])
+                        .collect();
+                    incident.sort_unstable();
+                    (node, hash_of(&(color, incident)))
+                })
+                .collect();
+
+            history.push(color_histogram(&node_colors));
+            history.push(color_histogram(&edge_colors));
+        }
+
+        format!("{:016x}", hash_of(&history))
+    }
+
+    /// Checks whether two hypergraphs are isomorphic.
+    ///
+    /// Node count, edge count, size distribution and `wl_hash` are compared first; if they all
+    /// match, a backtracking search looks for a node bijection mapping every edge onto an edge of
+    /// `other`. Weights and metadata are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The hypergraph to compare with.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the hypergraphs are isomorphic, or an error if the invariants
+    /// match but the hypergraphs have more than `MAX_ISOMORPHISM_NODES` nodes, where the exact search is not attempted.
+    pub fn is_isomorphic(&self, other: &HypergraphRust) -> Result<bool, HypergraphError> {
+        if self.num_nodes() != other.num_nodes()
+            || self.edge_list.len() != other.edge_list.len()
+            || self.distribution_sizes() != other.distribution_sizes()
+            || self.wl_hash(3) != other.wl_hash(3)
+        {
+            return Ok(false);
+        }
+        if self.num_nodes() > MAX_ISOMORPHISM_NODES {
+            return Err(HypergraphError::InvalidArgument(format!(
+                "Exact isomorphism is only checked up to {} nodes.", MAX_ISOMORPHISM_NODES
+            )));
+        }
+
+        // Firma di ciascun nodo: dimensioni ordinate degli archi incidenti
+        let signature = |hypergraph: &HypergraphRust, node: usize| {
+            let mut sizes: Vec<usize> = hypergraph.adj[&node]
+                .iter()
+                .filter_map(|edge_id| hypergraph.id_to_edge.get(edge_id))
+                .map(Vec::len)
+                .collect();
+            sizes.sort_unstable();
+            sizes
+        };
+
+        let mut nodes: Vec<usize> = self.adj.keys().copied().collect();
+        nodes.sort_unstable_by_key(|&node| (std::cmp::Reverse(self.adj[&node].len()), node));
+        let candidates: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|&node| {
+                let target = signature(self, node);
+                let mut matching: Vec<usize> = other.adj
+                    .keys()
+                    .copied()
+                    .filter(|&candidate| signature(other, candidate) == target)
+                    .collect();
+                matching.sort_unstable();
+                matching
+            })
+            .collect();
+
+        let mut mapping = HashMap::with_capacity(nodes.len());
+        let mut used = HashSet::with_capacity(nodes.len());
+        Ok(self.extend_isomorphism(other, &nodes, &candidates, &mut mapping, &mut used))
+    }
+
+    /// Backtracking step of `is_isomorphic`: maps `nodes[mapping.len()]` onto each free candidate,
+    /// keeping only the choices under which every fully mapped edge lands on an edge of `other`.
+    fn extend_isomorphism(
+        &self,
+        other: &HypergraphRust,
+        nodes: &[usize],
+        candidates: &[Vec<usize>],
+        mapping: &mut HashMap<usize, usize>,
+        used: &mut HashSet<usize>,
+    ) -> bool {
+        let depth = mapping.len();
+        if depth == nodes.len() {
+            return true;
+        }
+
+        let node = nodes[depth];
+        for &candidate in &candidates[depth] {
+            if used.contains(&candidate) {
+                continue;
+            }
+            mapping.insert(node, candidate);
+
+            let consistent = self.adj[&node]
+                .iter()
+                .filter_map(|edge_id| self.id_to_edge.get(edge_id))
+                .all(|edge| {
+                    let image: Option<Vec<usize>> = edge.iter().map(|n| mapping.get(n).copied()).collect();
+                    image.is_none_or(|mut image| {
+                        image.sort_unstable();
+                        other.edge_list.contains_key(&image)
+                    })
+                });
+
+            if consistent {
+                used.insert(candidate);
+                if self.extend_isomorphism(other, nodes, candidates, mapping, used) {
+                    return true;
+                }
+                used.remove(&candidate);
+            }
+            mapping.remove(&node);
+        }
+
+        false
+    }
+
+    /// Returns the node-edge incidence matrix in compressed sparse row (CSR) format.
+    ///
+    /// Rows are the nodes in ascending ID order (the same order used by `get_mapping`),
+    /// columns are the edges in lexicographic order, and every incidence has value `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(data, indices, indptr, shape)` with the CSR buffers and the
+    /// `(num_nodes, num_edges)` shape of the matrix.
+    pub fn incidence_csr(&self) -> (Vec<f64>, Vec<usize>, Vec<usize>, (usize, usize)) {
+        let mut nodes = self.get_nodes_without_metadata();
+        nodes.sort_unstable();
+        let row_of: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+
+        let mut edges: Vec<&Vec<usize>> = self.edge_list.keys().collect();
+        edges.sort_unstable();
+
+        // Scorrendo gli archi in ordine le colonne di ogni riga risultano già ordinate
+        let mut rows: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        for (col, edge) in edges.iter().enumerate() {
+            for node in edge.iter() {
+                rows[row_of[node]].push(col);
+            }
+        }
+
+        let mut indptr = Vec::with_capacity(nodes.len() + 1);
+        indptr.push(0);
+        let mut indices = Vec::new();
+        for row in rows {
+            indices.extend(row);
+            indptr.push(indices.len());
+        }
+        let data = vec![1.0; indices.len()];
+
+        (data, indices, indptr, (nodes.len(), edges.len()))
+    }
+
+    pub fn get_mapping(&self) -> Result<LabelEncoder, HypergraphError> {
         let nodes = self.get_nodes_without_metadata();
         
         if nodes.is_empty() {
-            return Err("Errore: nessun nodo trovato.".to_string());
+            return Err(HypergraphError::EmptyHypergraph.with_message("Errore: nessun nodo trovato."));
         }
 
         let mut encoder = LabelEncoder::new();
@@ -1055,6 +2710,37 @@ impl HypergraphRust {
     }
 }
 
+/// Hashes a value with `FxHasher`, which has no random key and a fixed algorithm, so that colors
+/// are comparable across hypergraphs, runs and builds.
+fn hash_of<T: std::hash::Hash>(value: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = rustc_hash::FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the sorted multiset of the colors of a coloring.
+fn color_histogram<K>(colors: &HashMap<K, u64>) -> Vec<u64> {
+    let mut histogram: Vec<u64> = colors.values().copied().collect();
+    histogram.sort_unstable();
+    histogram
+}
+
+/// Computes the binomial coefficient `C(n, k)` as a float, to avoid overflowing on large `n`.
+fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Checks whether the sorted slice `small` is a subset of the sorted slice `large`.
+fn is_sorted_subset(small: &[usize], large: &[usize]) -> bool {
+    let mut large_iter = large.iter();
+    small.iter().all(|node| large_iter.any(|other| other == node))
+}
+
 impl std::fmt::Display for HypergraphRust {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let dist_sizes = self.distribution_sizes();
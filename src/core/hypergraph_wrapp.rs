@@ -1,6 +1,7 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::{exceptions, prelude::*};
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde_json::Value;
 use std::collections::HashMap;
 use super::hypergraph_rust::HypergraphRust;
 
@@ -25,11 +26,33 @@ impl Hypergraph {
             weighted,
             weights,
             metadata    
-        );
+        ).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
         
         Ok(Hypergraph { inner: hypergraph })
     }
 
+    #[staticmethod]
+    #[pyo3(signature = (num_nodes, num_edges, weighted=false))]
+    pub fn with_capacity(num_nodes: usize, num_edges: usize, weighted: bool) -> Self {
+        Hypergraph { inner: HypergraphRust::with_capacity(num_nodes, num_edges, weighted) }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (matrix, node_ids, weighted_values=None))]
+    pub fn from_incidence_matrix(matrix: Vec<Vec<u8>>, node_ids: Vec<usize>, weighted_values: Option<Vec<f64>>) -> PyResult<Self> {
+        let hypergraph = HypergraphRust::from_incidence_matrix(matrix, node_ids, weighted_values)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: hypergraph })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (matrix, node_ids, threshold=0.0))]
+    pub fn from_adjacency_matrix(matrix: Vec<Vec<f64>>, node_ids: Vec<usize>, threshold: f64) -> PyResult<Self> {
+        let hypergraph = HypergraphRust::from_adjacency_matrix(matrix, node_ids, threshold)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: hypergraph })
+    }
+
     #[pyo3(signature = (edge, weight = None, metadata = None))]
     pub fn add_edge(
         &mut self,
@@ -38,7 +61,7 @@ impl Hypergraph {
         metadata: Option<HashMap<String, String>>,
     ) -> PyResult<()> {
         self.inner.add_edge(edge, weight, metadata).
-            map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
     }
 
     #[pyo3(signature = (edges, weights=None, metadata=None))]
@@ -49,7 +72,35 @@ impl Hypergraph {
         metadata: Option<HashMap<String, String>>,
     ) -> PyResult<()> {
         self.inner.add_edges(edges, weights, metadata).
-            map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    #[pyo3(signature = (edges, weights=None, metadata=None))]
+    pub fn add_edges_iter(
+        &mut self,
+        edges: &Bound<'_, PyAny>,
+        weights: Option<&Bound<'_, PyAny>>,
+        metadata: Option<HashMap<String, String>>,
+    ) -> PyResult<()> {
+        let to_py_err = |e: super::error::HypergraphError| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string());
+        let length_err = || PyErr::new::<pyo3::exceptions::PyValueError, _>("The number of edges and weights must be the same.");
+
+        self.inner.check_weights_argument(weights.is_some()).map_err(to_py_err)?;
+        let mut weights = weights.map(|w| w.iter()).transpose()?;
+
+        for (i, edge) in edges.iter()?.enumerate() {
+            let edge: Vec<usize> = edge?.extract()?;
+            let weight = match weights.as_mut() {
+                Some(w) => Some(w.next().ok_or_else(length_err)??.extract::<f64>()?),
+                None => None,
+            };
+            self.inner.add_indexed_edge(i, edge, weight, metadata.as_ref()).map_err(to_py_err)?;
+        }
+
+        if weights.as_mut().is_some_and(|w| w.next().is_some()) {
+            return Err(length_err());
+        }
+        Ok(())
     }
 
     pub fn add_node(&mut self, node: usize) {
@@ -94,28 +145,70 @@ impl Hypergraph {
         size: Option<usize>,
         up_to: bool
     ) -> PyResult<Py<PyList>> {
-        let edges = self.inner.get_edges(ids, order, size, up_to);
+        let edges = self.inner.get_edges(ids, order, size, up_to)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
         let py_edges = PyList::new_bound(py, edges);
         Ok(py_edges.into())
     }
 
+    #[pyo3(signature = (order = None, size = None, up_to = false))]
+    pub fn get_edges_with_weights(
+        &self,
+        py: Python,
+        order: Option<usize>,
+        size: Option<usize>,
+        up_to: bool
+    ) -> PyResult<Vec<(Py<PyTuple>, f64)>> {
+        let edges = self.inner.get_edges_with_weights(order, size, up_to)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(edges
+            .into_iter()
+            .map(|(edge, weight)| (PyTuple::new_bound(py, edge).unbind(), weight))
+            .collect())
+    }
+
     pub fn get_edges_metadata(&self) -> Vec<(Vec<usize>, HashMap<String, String>)> {
         self.inner.get_edges_metadata()
     }
 
+    pub fn filter_edges_by_meta(&self, key: String, value: String) -> Vec<Vec<usize>> {
+        self.inner.filter_edges_by_meta(key, value)
+    }
+
+    pub fn get_adjacency(&self) -> HashMap<usize, Vec<usize>> {
+        self.inner.get_adjacency()
+    }
+
+    pub fn get_all_node_metadata(&self) -> HashMap<usize, HashMap<String, String>> {
+        self.inner.get_all_node_metadata()
+    }
+
+    pub fn nodes_with_meta(&self, key: String, value: String) -> Vec<usize> {
+        self.inner.nodes_with_meta(key, value)
+    }
+
     pub fn is_weighted(&self) -> bool {
         self.inner.is_weighted()
     }
 
     pub fn remove_edge(&mut self, _py: Python, edge: Vec<usize>) -> PyResult<()> {
         self.inner.remove_edge(edge)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
     }
 
     pub fn remove_edges(&mut self, _py: Python, edges: Vec<Vec<usize>>) {
         self.inner.remove_edges(edges);
     }
 
+    pub fn restrict_to_sizes(&mut self, sizes: Vec<usize>) {
+        self.inner.restrict_to_sizes(sizes);
+    }
+
+    #[pyo3(signature = (min_weight, remove_isolated = false))]
+    pub fn prune_by_weight(&mut self, min_weight: f64, remove_isolated: bool) {
+        self.inner.prune_by_weight(min_weight, remove_isolated);
+    }
+
     #[pyo3(signature = (node, keep_edges = None))]
     pub fn remove_node(
         &mut self,
@@ -125,7 +218,7 @@ impl Hypergraph {
     ) -> PyResult<()> {
         let keep_edges = keep_edges.unwrap_or(false);
         self.inner.remove_node(node, keep_edges)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _> (e))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _> (e.to_string()))
     }
 
     #[pyo3(signature = (nodes, keep_edges = None))]
@@ -140,6 +233,25 @@ impl Hypergraph {
         self.inner.remove_nodes(nodes, keep_edges);
     }
 
+    pub fn contract_nodes(&mut self, nodes: Vec<usize>, into: usize) -> PyResult<()> {
+        self.inner.contract_nodes(nodes, into)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    #[pyo3(signature = (num_swaps, seed=None))]
+    pub fn double_edge_swap(&mut self, num_swaps: usize, seed: Option<u64>) -> PyResult<usize> {
+        self.inner.double_edge_swap(num_swaps, seed)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn isolated_nodes(&self) -> Vec<usize> {
+        self.inner.isolated_nodes()
+    }
+
+    pub fn remove_isolated_nodes(&mut self) {
+        self.inner.remove_isolated_nodes();
+    }
+
     pub fn is_uniform(&self) -> bool {
         return self.inner.is_uniform();
     }
@@ -156,13 +268,14 @@ impl Hypergraph {
         return self.inner.num_nodes();
     }
 
-    #[pyo3(signature = (order = None, size = None, up_to = false))]
+    #[pyo3(signature = (order = None, size = None, up_to = false, multiplicity = false))]
     pub fn num_edges(
         &self,
         _py: Python,
         order: Option<usize>,
         size: Option<usize>,
         up_to: bool,
+        multiplicity: bool,
     ) -> PyResult<usize> {
         // Controllo se sia `order` che `size` sono specificati
         if order.is_some() && size.is_some() {
@@ -172,12 +285,17 @@ impl Hypergraph {
         }
 
         // Chiama la funzione Rust `num_edges` interna con i parametri corretti
-        match self.inner.num_edges(order, size, up_to) {
+        match self.inner.num_edges(order, size, up_to, multiplicity) {
             Ok(num) => Ok(num),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e)),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())),
         }
     }
 
+    pub fn multiplicity(&self, edge: Vec<usize>) -> PyResult<f64> {
+        self.inner.multiplicity(edge)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
     pub fn check_edge(&self, edge: Vec<usize>) -> bool {
         self.inner.check_edge(edge)
     }
@@ -217,6 +335,17 @@ impl Hypergraph {
         })
     }
 
+    fn distribution_orders(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let dist = self.inner.distribution_orders();
+            let dict = PyDict::new_bound(py);
+            for (k, v) in dist {
+                dict.set_item(k, v)?;
+            }
+            Ok(dict.to_object(py))
+        })
+    }
+
     pub fn get_orders(&self, py: Python) -> PyResult<PyObject> {
         let orders = self.inner.get_orders();
         Ok(PyList::new_bound(py, orders).into())
@@ -228,18 +357,46 @@ impl Hypergraph {
                 Ok(PyString::new_bound(py, value).into_py(py))
             }
             Err(err_msg) => {
-                Err(PyValueError::new_err(err_msg))
+                Err(PyValueError::new_err(err_msg.to_string()))
             }
         }
     }
 
-    #[pyo3(signature = (node, order=None, size=None))]
+    pub fn num_nodes_meta(&self) -> usize {
+        self.inner.num_nodes_meta()
+    }
+
+    pub fn num_edges_meta(&self) -> usize {
+        self.inner.num_edges_meta()
+    }
+
+    pub fn set_attr_json(&mut self, obj_id: usize, attributes: &Bound<'_, PyDict>) -> PyResult<()> {
+        let mut typed = HashMap::new();
+        for (key, value) in attributes.iter() {
+            typed.insert(key.extract::<String>()?, py_to_json(&value)?);
+        }
+        self.inner.set_attr_json(obj_id, typed)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn get_attr_json(&self, py: Python, obj_id: usize) -> PyResult<PyObject> {
+        let attributes = self.inner.get_attr_json(obj_id)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        let dict = PyDict::new_bound(py);
+        for (key, value) in &attributes {
+            dict.set_item(key, json_to_py(py, value)?)?;
+        }
+        Ok(dict.into())
+    }
+
+    #[pyo3(signature = (node, order=None, size=None, ids=false))]
     fn get_incident_edges(
         &self,
         py: Python,
         node: usize,
         order: Option<usize>,
         size: Option<usize>,
+        ids: bool,
     ) -> PyResult<Py<PyList>> {
         // Gestisci l'errore nel caso in cui sia `order` che `size` siano specificati
         if order.is_some() && size.is_some() {
@@ -248,19 +405,42 @@ impl Hypergraph {
             ));
         }
 
+        if ids {
+            return self.inner.get_incident_edge_ids(node, order, size)
+                .map(|edge_ids| PyList::new_bound(py, edge_ids).into())
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()));
+        }
+
         match self.inner.get_incident_edges(node, order, size) {
             Ok(edges) => Ok(PyList::new_bound(py, edges).into()),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e)),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())),
         }
     }
 
+    #[pyo3(signature = (node, order=None, size=None))]
+    fn get_incident_weights(
+        &self,
+        node: usize,
+        order: Option<usize>,
+        size: Option<usize>,
+    ) -> PyResult<Vec<f64>> {
+        if order.is_some() && size.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Order and size cannot both be specified.",
+            ));
+        }
+
+        self.inner.get_incident_weights(node, order, size)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
     pub fn get_weight(&self, py: Python, edge: Vec<usize>) -> PyResult<PyObject> {
         match self.inner.get_weight(edge) {
             Ok(weight) => {
                 Ok(weight.into_py(py))
             }
             Err(err_msg) => {
-                Err(PyValueError::new_err(err_msg))
+                Err(PyValueError::new_err(err_msg.to_string()))
             }
         }
     }
@@ -269,10 +449,67 @@ impl Hypergraph {
         
         match self.inner.set_weight(edge, weight) {
             Ok(_) => Ok(()),  
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e)),  
+            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),  
         }
     }
 
+    pub fn set_weight_vec(&mut self, edge: Vec<usize>, weights: Vec<f64>) -> PyResult<()> {
+        self.inner.set_weight_vec(edge, weights)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn get_weight_vec(&self, edge: Vec<usize>) -> PyResult<Option<Vec<f64>>> {
+        self.inner.get_weight_vec(edge)
+            .map(|weights| weights.cloned())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn size_entropy(&self) -> f64 {
+        self.inner.size_entropy()
+    }
+
+    pub fn density(&self) -> f64 {
+        self.inner.density()
+    }
+
+    pub fn num_incidences(&self) -> usize {
+        self.inner.num_incidences()
+    }
+
+    pub fn size_stats(&self) -> PyResult<(f64, f64, usize, usize)> {
+        self.inner.size_stats()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.inner.total_weight()
+    }
+
+    pub fn normalize_weights(&mut self) -> PyResult<()> {
+        self.inner.normalize_weights()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    #[pyo3(signature = (aggregate = "sum"))]
+    pub fn collapse_edges(&mut self, aggregate: &str) -> PyResult<()> {
+        self.inner.collapse_edges(aggregate)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn common_neighbors(&self, u: usize, v: usize) -> PyResult<Vec<usize>> {
+        self.inner.common_neighbors(u, v)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    #[pyo3(signature = (u, v, weighted = false))]
+    pub fn co_occurrence(&self, u: usize, v: usize, weighted: bool) -> f64 {
+        self.inner.co_occurrence(u, v, weighted)
+    }
+
+    pub fn edges_containing(&self, nodes: Vec<usize>) -> Vec<Vec<usize>> {
+        self.inner.edges_containing(nodes)
+    }
+
     #[pyo3(signature = (node, order = None, size = None))]
     pub fn get_neighbors(
         &self,
@@ -289,7 +526,7 @@ impl Hypergraph {
 
         match self.inner.get_neighbors(node, order, size) {
             Ok(neighbors) => Ok(PyList::new_bound(py, neighbors).into()),
-            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e)),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())),
         }
     }
     
@@ -307,7 +544,7 @@ impl Hypergraph {
                 Ok(weights)
             }
             Err(err_msg) => {
-                Err(PyErr::new::<PyValueError, _>(err_msg))
+                Err(PyErr::new::<PyValueError, _>(err_msg.to_string()))
             }
         }
     }
@@ -316,7 +553,7 @@ impl Hypergraph {
     pub fn get_mapping(&self, py: Python) -> PyResult<PyObject> {
         self.inner
             .get_mapping()
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
             .map(|m| m.into_py(py))
     }
 
@@ -326,12 +563,146 @@ impl Hypergraph {
     }
 
     pub fn subhypergraph(&self, nodes: Vec<usize>) -> PyResult<Hypergraph> {
-        let subgraph = self.inner.subhypergraph(nodes);
+        let subgraph = self.inner.subhypergraph(nodes)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: subgraph })
+    }
+
+    pub fn subhypergraph_by_edges(&self, edge_ids: Vec<usize>) -> PyResult<Hypergraph> {
+        let subgraph = self.inner.subhypergraph_by_edges(edge_ids)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
         Ok(Hypergraph { inner: subgraph })
     }
 
+    #[pyo3(signature = (orders = None, sizes = None, keep_nodes = true))]
+    pub fn subhypergraph_by_orders(
+        &self,
+        orders: Option<Vec<usize>>,
+        sizes: Option<Vec<usize>>,
+        keep_nodes: bool,
+    ) -> PyResult<Hypergraph> {
+        self.inner.subhypergraph_by_orders(orders, sizes, keep_nodes)
+            .map(|subgraph| Hypergraph { inner: subgraph })
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    #[pyo3(signature = (seed=None))]
+    pub fn bootstrap(&self, seed: Option<u64>) -> Hypergraph {
+        Hypergraph { inner: self.inner.bootstrap(seed) }
+    }
+
+    pub fn adjacency_matrix(&self) -> (Vec<usize>, Vec<Vec<f64>>) {
+        self.inner.adjacency_matrix()
+    }
+
+    pub fn clique_expansion(&self) -> PyResult<Hypergraph> {
+        Ok(Hypergraph { inner: self.inner.clique_expansion() })
+    }
+
+    pub fn star_expansion(&self) -> PyResult<Hypergraph> {
+        Ok(Hypergraph { inner: self.inner.star_expansion() })
+    }
+
+    pub fn star(&self, node: usize) -> PyResult<Hypergraph> {
+        let star = self.inner.star(node)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: star })
+    }
+
+    pub fn simplicial_closure(&self) -> PyResult<Hypergraph> {
+        let closure = self.inner.simplicial_closure()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: closure })
+    }
+
+    pub fn is_simplicial(&self) -> bool {
+        self.inner.is_simplicial()
+    }
+
+    pub fn maximal_edges(&self) -> Vec<Vec<usize>> {
+        self.inner.maximal_edges()
+    }
+
+    #[pyo3(signature = (iterations = 3))]
+    pub fn wl_hash(&self, iterations: usize) -> String {
+        self.inner.wl_hash(iterations)
+    }
+
+    pub fn is_isomorphic(&self, other: &Hypergraph) -> PyResult<bool> {
+        self.inner.is_isomorphic(&other.inner)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Returns `(data, indices, indptr, shape)`, ready for
+    /// `scipy.sparse.csr_matrix((data, indices, indptr), shape=shape)`.
+    /// Rows follow ascending node IDs, columns follow the edges in lexicographic order.
+    pub fn incidence_scipy(&self) -> (Vec<f64>, Vec<usize>, Vec<usize>, (usize, usize)) {
+        self.inner.incidence_csr()
+    }
+
     fn __str__(&self) -> PyResult<String> {
         Ok(self.inner.to_string())
     }
 }
 
+/// Converts a Python object made of dicts, lists and scalars into a JSON value.
+fn py_to_json(obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if obj.is_none() {
+        Ok(Value::Null)
+    } else if obj.is_instance_of::<PyBool>() {
+        Ok(Value::Bool(obj.extract()?))
+    } else if obj.is_instance_of::<PyInt>() {
+        match obj.extract::<i64>() {
+            Ok(value) => Ok(Value::from(value)),
+            Err(_) => Ok(Value::from(obj.extract::<u64>()?)),
+        }
+    } else if obj.is_instance_of::<PyFloat>() {
+        let value: f64 = obj.extract()?;
+        serde_json::Number::from_f64(value)
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err(format!("Cannot store {} as a JSON number.", value)))
+    } else if let Ok(value) = obj.downcast::<PyString>() {
+        Ok(Value::String(value.to_str()?.to_string()))
+    } else if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut map = serde_json::Map::new();
+        for (key, value) in dict.iter() {
+            map.insert(key.extract::<String>()?, py_to_json(&value)?);
+        }
+        Ok(Value::Object(map))
+    } else if obj.is_instance_of::<PyList>() || obj.is_instance_of::<PyTuple>() {
+        obj.iter()?
+            .map(|item| py_to_json(&item?))
+            .collect::<PyResult<Vec<Value>>>()
+            .map(Value::Array)
+    } else {
+        Err(PyValueError::new_err(format!("Unsupported attribute type: {}", obj.get_type().name()?)))
+    }
+}
+
+/// Converts a JSON value into the corresponding native Python object.
+fn json_to_py(py: Python, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(b) => b.into_py(py),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.into_py(py),
+            (None, Some(u)) => u.into_py(py),
+            _ => n.as_f64().unwrap_or(f64::NAN).into_py(py),
+        },
+        Value::String(s) => s.into_py(py),
+        Value::Array(items) => {
+            let list = PyList::empty_bound(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            list.into()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new_bound(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item)?)?;
+            }
+            dict.into()
+        }
+    })
+}
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 #[pyclass]
 pub struct LabelEncoder {
     mapping: HashMap<usize, usize>,
+    inverse_mapping: HashMap<usize, usize>,
 }
 
 #[pymethods]
@@ -12,6 +13,7 @@ impl LabelEncoder {
     pub fn new() -> Self {
         LabelEncoder {
             mapping: HashMap::new(),
+            inverse_mapping: HashMap::new(),
         }
     }
 
@@ -20,6 +22,7 @@ impl LabelEncoder {
         node_sort.sort();
         for (i, node) in node_sort.iter().enumerate() {
             self.mapping.insert(*node, i);
+            self.inverse_mapping.insert(i, *node);
         }
     }
 
@@ -28,16 +31,14 @@ impl LabelEncoder {
     }
 
     pub fn inverse_transform(&self, index: usize) -> Option<usize> {
-        self.mapping.iter().find_map(|(&node, &i)| {
-            if i == index {
-                Some(node)
-            } else {
-                None
-            }
-        })
+        self.inverse_mapping.get(&index).cloned()
     }
 
     pub fn get_mapping(&self) -> HashMap<usize, usize> {
         self.mapping.clone()
     }
+
+    pub fn get_inverse_mapping(&self) -> HashMap<usize, usize> {
+        self.inverse_mapping.clone()
+    }
 }
@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 use core::hash::Hash;
 use std::fmt::Display;
+use serde_json::Value;
+use super::error::HypergraphError;
 
 #[derive(Clone)]
 pub struct MetaHandler<T> {
     id : usize,
     id_2_obj : HashMap<usize, T>,
     obj_2_id : HashMap<T, usize>,
-    attr : HashMap <usize, HashMap<String, String>>
+    attr : HashMap <usize, HashMap<String, String>>,
+    attr_json : HashMap <usize, HashMap<String, Value>>
 }
 
 impl <T> MetaHandler <T>
@@ -20,6 +23,18 @@ where
             id_2_obj : HashMap::new(),
             obj_2_id : HashMap::new(),
             attr : HashMap::new(),
+            attr_json : HashMap::new(),
+        }
+    }
+
+    /// Creates a handler with room for `capacity` objects without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        MetaHandler{
+            id : 0 ,
+            id_2_obj : HashMap::with_capacity(capacity),
+            obj_2_id : HashMap::with_capacity(capacity),
+            attr : HashMap::with_capacity(capacity),
+            attr_json : HashMap::new(),
         }
     }
 
@@ -59,12 +74,12 @@ where
         obj_id
     }
 
-    pub fn get_id(&self, obj: &T) -> Result<usize, String> {
-        self.obj_2_id.get(obj).cloned().ok_or_else(|| format!("No object {}.", obj))
+    pub fn get_id(&self, obj: &T) -> Result<usize, HypergraphError> {
+        self.obj_2_id.get(obj).cloned().ok_or_else(|| HypergraphError::ObjectNotFound(obj.to_string()).with_message(format!("No object {}.", obj)))
     }
 
 
-    pub fn set_attr(&mut self, obj: &T, new_attr: HashMap<String, String>) -> Result<(), String> {
+    pub fn set_attr(&mut self, obj: &T, new_attr: HashMap<String, String>) -> Result<(), HypergraphError> {
         let id = self.get_id(obj)?;
         let attributes = self.attr.entry(id).or_insert_with(HashMap::new);
         for (key, value) in new_attr {
@@ -73,9 +88,38 @@ where
         Ok(())
     }
 
-    pub fn get_attr(&self, obj: &T) -> Result<&HashMap<String, String>, String> {
+    pub fn get_attr(&self, obj: &T) -> Result<&HashMap<String, String>, HypergraphError> {
         let idx = self.get_id(obj)?;
-        self.attr.get(&idx).ok_or_else(|| format!("No object {}.", obj))
+        self.attr.get(&idx).ok_or_else(|| HypergraphError::ObjectNotFound(obj.to_string()).with_message(format!("No object {}.", obj)))
+    }
+
+    /// Stores typed attributes for an object, next to its string attributes.
+    pub fn set_attr_json(&mut self, obj: &T, new_attr: HashMap<String, Value>) -> Result<(), HypergraphError> {
+        let id = self.get_id(obj)?;
+        self.attr_json.entry(id).or_default().extend(new_attr);
+        Ok(())
+    }
+
+    /// Returns all attributes of an object as JSON values: string attributes become
+    /// JSON strings and typed attributes with the same key take precedence.
+    pub fn get_attr_json(&self, obj: &T) -> Result<HashMap<String, Value>, HypergraphError> {
+        let id = self.get_id(obj)?;
+        let mut attributes: HashMap<String, Value> = self.attr
+            .get(&id)
+            .map(|attrs| attrs.iter().map(|(k, v)| (k.clone(), Value::String(v.clone()))).collect())
+            .unwrap_or_default();
+        if let Some(typed) = self.attr_json.get(&id) {
+            attributes.extend(typed.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        Ok(attributes)
+    }
+
+    /// Counts the stored objects whose `"type"` attribute equals `type_name`.
+    pub fn count_by_type(&self, type_name: &str) -> usize {
+        self.attr
+            .values()
+            .filter(|attrs| attrs.get("type").is_some_and(|t| t == type_name))
+            .count()
     }
 
     pub fn get_object_by_id(&self, obj_id: usize) -> Option<&T> {
@@ -94,13 +138,14 @@ where
         self.attr.insert(obj_id, attr);
     }
 
-    pub fn remove_object(&mut self, obj: &T) -> Result<(), String> {
+    pub fn remove_object(&mut self, obj: &T) -> Result<(), HypergraphError> {
         if let Some(idx) = self.obj_2_id.remove(obj) {
             self.id_2_obj.remove(&idx);
             self.attr.remove(&idx);
+            self.attr_json.remove(&idx);
             Ok(())
         } else {
-            Err(format!("No object {}.", obj))
+            Err(HypergraphError::ObjectNotFound(obj.to_string()).with_message(format!("No object {}.", obj)))
         }
     }
 
@@ -1,4 +1,10 @@
+pub mod error;
 pub mod hypergraph_rust;
 pub mod hypergraph_wrapp;
 pub mod meta_handler;
-pub mod label_encoder;
\ No newline at end of file
+pub mod label_encoder;
+pub mod rng;
+pub mod temporal_hypergraph_rust;
+pub mod temporal_hypergraph_wrapp;
+pub mod directed_hypergraph_rust;
+pub mod directed_hypergraph_wrapp;
\ No newline at end of file
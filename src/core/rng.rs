@@ -0,0 +1,16 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Builds the random number generator shared by the randomizing methods, generators and stochastic processes.
+/// 
+/// # Arguments
+/// * `seed` - Optional seed. With a seed the generator is deterministic, otherwise it is seeded from system entropy
+/// 
+/// # Returns
+/// * `StdRng` - A single generator to be reused for every draw of a run
+pub fn rng_from_seed(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    }
+}
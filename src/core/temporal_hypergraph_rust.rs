@@ -0,0 +1,159 @@
+use super::{error::HypergraphError, hypergraph_rust::HypergraphRust};
+use std::collections::BTreeMap;
+
+/// A temporal hypergraph, where every edge is observed at an integer time.
+#[derive(Clone)]
+pub struct TemporalHypergraphRust {
+    /// Indicates whether the hypergraph is weighted.
+    weighted: bool,
+    /// Static hypergraph of the edges observed at each time.
+    snapshots: BTreeMap<usize, HypergraphRust>,
+}
+
+impl TemporalHypergraphRust {
+    /// Creates a new temporal hypergraph from an optional list of timestamped edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge_list`: The `(time, edge)` pairs to be added.
+    /// * `weighted`: Whether the hypergraph is weighted.
+    /// * `weights`: The weights of the edges. Defaults to `1.0` for every edge.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the temporal hypergraph, or an error if an edge is empty or `weights` and `edge_list` have different lengths.
+    pub fn new(
+        edge_list: Option<Vec<(usize, Vec<usize>)>>,
+        weighted: bool,
+        weights: Option<Vec<f64>>,
+    ) -> Result<TemporalHypergraphRust, HypergraphError> {
+        let mut temporal = TemporalHypergraphRust {
+            weighted,
+            snapshots: BTreeMap::new(),
+        };
+
+        if let Some(edges) = edge_list {
+            let weights = weights.unwrap_or_else(|| vec![1.0; edges.len()]);
+            if weights.len() != edges.len() {
+                return Err(HypergraphError::InvalidArgument(
+                    "The number of edges and weights must be the same.".to_string(),
+                ));
+            }
+
+            for ((time, edge), weight) in edges.into_iter().zip(weights) {
+                temporal.add_edge(edge, time, Some(weight))?;
+            }
+        }
+
+        Ok(temporal)
+    }
+
+    /// Adds an edge observed at the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `edge`: The edge to be added.
+    /// * `time`: The time at which the edge is observed.
+    /// * `weight`: The weight of the edge. If the hypergraph is not weighted, this argument is ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the edge was added successfully.
+    /// * `Err(HypergraphError)` if the edge is empty.
+    ///
+    /// # Notes
+    ///
+    /// Each time has its own static hypergraph, so an edge repeated at the same time follows
+    /// the rules of `HypergraphRust::add_edge` (multiplicity if unweighted, new weight otherwise).
+    pub fn add_edge(&mut self, edge: Vec<usize>, time: usize, weight: Option<f64>) -> Result<(), HypergraphError> {
+        if edge.is_empty() {
+            return Err(HypergraphError::EmptyEdge);
+        }
+
+        let weighted = self.weighted;
+        self.snapshots
+            .entry(time)
+            .or_insert_with(|| HypergraphRust::empty(weighted))
+            .add_edge(edge, weight, None)
+    }
+
+    /// Returns the edges observed at the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: The time of interest.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector with the edges observed at `time`, empty if there are none.
+    pub fn get_edges_at(&self, time: usize) -> Vec<Vec<usize>> {
+        let mut edges: Vec<Vec<usize>> = self.snapshots
+            .get(&time)
+            .map(|snapshot| snapshot.edge_list.keys().cloned().collect())
+            .unwrap_or_default();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Returns the first and last time at which an edge is observed.
+    ///
+    /// # Returns
+    ///
+    /// `Some((t_min, t_max))`, or `None` if the hypergraph has no edges.
+    pub fn time_range(&self) -> Option<(usize, usize)> {
+        let first = self.snapshots.keys().next()?;
+        let last = self.snapshots.keys().next_back()?;
+        Some((*first, *last))
+    }
+
+    /// Returns `true` if the hypergraph is weighted, `false` otherwise.
+    pub fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+
+    /// Aggregates the whole temporal hypergraph into a static one.
+    ///
+    /// # Returns
+    ///
+    /// A weighted `HypergraphRust` where the weight of every edge is the sum, over time, of its
+    /// multiplicities (or of its weights, if the temporal hypergraph is weighted).
+    pub fn aggregate(&self) -> HypergraphRust {
+        aggregate_snapshots(self.snapshots.values())
+    }
+
+    /// Aggregates the edges observed in the inclusive time window `[t_start, t_end]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `t_start`: The first time of the window.
+    /// * `t_end`: The last time of the window.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a weighted `HypergraphRust` with the multiplicities (or weights)
+    /// of the edges summed over the window, or an error if `t_start` is after `t_end`.
+    pub fn slice(&self, t_start: usize, t_end: usize) -> Result<HypergraphRust, HypergraphError> {
+        if t_start > t_end {
+            return Err(HypergraphError::InvalidArgument(format!(
+                "Invalid time window: start {} is after end {}.", t_start, t_end
+            )));
+        }
+
+        Ok(aggregate_snapshots(self.snapshots.range(t_start..=t_end).map(|(_, snapshot)| snapshot)))
+    }
+}
+
+/// Sums a sequence of snapshots into a single weighted hypergraph.
+fn aggregate_snapshots<'a, I>(snapshots: I) -> HypergraphRust
+where
+    I: IntoIterator<Item = &'a HypergraphRust>,
+{
+    let mut aggregated = HypergraphRust::empty(true);
+    for snapshot in snapshots {
+        for (edge, &weight) in &snapshot.edge_list {
+            let total = aggregated.edge_list.get(edge).copied().unwrap_or(0.0) + weight;
+            let _ = aggregated.add_edge(edge.clone(), Some(total), None);
+        }
+    }
+    aggregated
+}
@@ -0,0 +1,53 @@
+use pyo3::prelude::*;
+use super::hypergraph_wrapp::Hypergraph;
+use super::temporal_hypergraph_rust::TemporalHypergraphRust;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct TemporalHypergraph {
+    pub inner: TemporalHypergraphRust,
+}
+
+#[pymethods]
+impl TemporalHypergraph {
+    #[new]
+    #[pyo3(signature = (edge_list=None, weighted=false, weights=None))]
+    pub fn new(
+        edge_list: Option<Vec<(usize, Vec<usize>)>>,
+        weighted: bool,
+        weights: Option<Vec<f64>>,
+    ) -> PyResult<Self> {
+        let temporal = TemporalHypergraphRust::new(edge_list, weighted, weights)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        Ok(TemporalHypergraph { inner: temporal })
+    }
+
+    #[pyo3(signature = (edge, time, weight = None))]
+    pub fn add_edge(&mut self, edge: Vec<usize>, time: usize, weight: Option<f64>) -> PyResult<()> {
+        self.inner.add_edge(edge, time, weight)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    pub fn get_edges_at(&self, time: usize) -> Vec<Vec<usize>> {
+        self.inner.get_edges_at(time)
+    }
+
+    pub fn time_range(&self) -> Option<(usize, usize)> {
+        self.inner.time_range()
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.inner.is_weighted()
+    }
+
+    pub fn aggregate(&self) -> PyResult<Hypergraph> {
+        Ok(Hypergraph { inner: self.inner.aggregate() })
+    }
+
+    pub fn slice(&self, t_start: usize, t_end: usize) -> PyResult<Hypergraph> {
+        let sliced = self.inner.slice(t_start, t_end)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Hypergraph { inner: sliced })
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use rand::Rng;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+use crate::core::rng::rng_from_seed;
+
+/// Simulates a discrete-time SIS (susceptible-infected-susceptible) contagion on a hypergraph.
+/// 
+/// At every step each infected member of a hyperedge independently tries to infect the
+/// susceptible members of that edge with probability `beta`, so a susceptible node exposed
+/// to `k` infected co-members (summed over its incident edges) becomes infected with
+/// probability `1 - (1 - beta)^k`. Infected nodes recover with probability `mu`.
+/// All nodes are updated synchronously.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `beta` - Per-contact infection probability, in `[0, 1]`
+/// * `mu` - Recovery probability, in `[0, 1]`
+/// * `initial_infected` - Nodes infected at step 0
+/// * `steps` - Number of steps to simulate
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - Sorted infected nodes at each step, starting from the initial state
+/// * `Err(HypergraphError)` - Error if a probability is out of range or an initial node is not in the hypergraph
+pub fn sis_contagion_rust(
+    hypergraph: &HypergraphRust,
+    beta: f64,
+    mu: f64,
+    initial_infected: Vec<usize>,
+    steps: usize,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<usize>>, HypergraphError> {
+    if !(0.0..=1.0).contains(&beta) || !(0.0..=1.0).contains(&mu) {
+        return Err(HypergraphError::InvalidArgument("beta and mu must be probabilities in [0, 1].".to_string()));
+    }
+    if let Some(&node) = initial_infected.iter().find(|&&node| !hypergraph.check_node(node)) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+
+    let mut rng = rng_from_seed(seed);
+
+    let mut infected: BTreeSet<usize> = initial_infected.into_iter().collect();
+    let mut history = Vec::with_capacity(steps + 1);
+    history.push(infected.iter().copied().collect::<Vec<usize>>());
+
+    for _ in 0..steps {
+        // Number of infected contacts seen by each susceptible node in this step
+        let mut exposure: BTreeMap<usize, i32> = BTreeMap::new();
+        for edge in hypergraph.get_all_edges() {
+            let k = edge.iter().filter(|node| infected.contains(node)).count() as i32;
+            if k == 0 {
+                continue;
+            }
+            for node in edge.iter().filter(|node| !infected.contains(node)) {
+                *exposure.entry(*node).or_insert(0) += k;
+            }
+        }
+
+        let mut next: BTreeSet<usize> = infected
+            .iter()
+            .copied()
+            .filter(|_| rng.gen::<f64>() >= mu)
+            .collect();
+
+        for (node, k) in exposure {
+            if rng.gen::<f64>() < 1.0 - (1.0 - beta).powi(k) {
+                next.insert(node);
+            }
+        }
+
+        infected = next;
+        history.push(infected.iter().copied().collect());
+    }
+
+    Ok(history)
+}
+
+/// Simulates a threshold-based complex contagion on a hypergraph.
+/// 
+/// An inactive node becomes active when, in at least one of its incident hyperedges, the
+/// fraction of active members strictly exceeds the node's threshold. Activation is monotone
+/// and all nodes are updated synchronously. The simulation stops early once a step
+/// activates no new node.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `thresholds` - Activation threshold for specific nodes
+/// * `default_threshold` - Threshold used for nodes missing from `thresholds`
+/// * `initial_active` - Nodes active at step 0
+/// * `steps` - Maximum number of steps to simulate
+/// 
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - Sorted active nodes at each step, starting from the initial state
+/// * `Err(HypergraphError)` - Error if an initial node is not in the hypergraph
+pub fn threshold_contagion_rust(
+    hypergraph: &HypergraphRust,
+    thresholds: HashMap<usize, f64>,
+    default_threshold: f64,
+    initial_active: Vec<usize>,
+    steps: usize,
+) -> Result<Vec<Vec<usize>>, HypergraphError> {
+    if let Some(&node) = initial_active.iter().find(|&&node| !hypergraph.check_node(node)) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+
+    let mut active: BTreeSet<usize> = initial_active.into_iter().collect();
+    let mut history = vec![active.iter().copied().collect::<Vec<usize>>()];
+
+    for _ in 0..steps {
+        let mut newly_active = BTreeSet::new();
+        for edge in hypergraph.get_all_edges() {
+            let fraction = edge.iter().filter(|node| active.contains(node)).count() as f64 / edge.len() as f64;
+            for &node in edge.iter().filter(|node| !active.contains(node)) {
+                let threshold = thresholds.get(&node).copied().unwrap_or(default_threshold);
+                if fraction > threshold {
+                    newly_active.insert(node);
+                }
+            }
+        }
+
+        if newly_active.is_empty() {
+            break;
+        }
+
+        active.extend(newly_active);
+        history.push(active.iter().copied().collect());
+    }
+
+    Ok(history)
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use crate::core::hypergraph_wrapp::Hypergraph;
+use super::contagion::*;
+use super::randwalk::*;
+
+/// Python wrapper for simulating an SIS contagion on a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `beta` - Per-contact infection probability
+/// * `mu` - Recovery probability
+/// * `initial_infected` - Nodes infected at step 0
+/// * `steps` - Number of steps to simulate
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<usize>>>` - Infected nodes at each step, starting from the initial state
+/// * Raises `PyValueError` if the simulation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, beta, mu, initial_infected, steps, seed=None), name = "sis_contagion")]
+pub fn sis_contagion(
+    hypergraph: &Hypergraph,
+    beta: f64,
+    mu: f64,
+    initial_infected: Vec<usize>,
+    steps: usize,
+    seed: Option<u64>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    sis_contagion_rust(hypergraph_rust, beta, mu, initial_infected, steps, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error simulating SIS contagion: {}", e))
+    })
+}
+
+/// Python wrapper for simulating a threshold-based complex contagion on a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `thresholds` - Activation threshold for specific nodes
+/// * `initial_active` - Nodes active at step 0
+/// * `steps` - Maximum number of steps to simulate
+/// * `default_threshold` - Threshold used for nodes missing from `thresholds`
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<usize>>>` - Active nodes at each step, stopping early at a fixed point
+/// * Raises `PyValueError` if the simulation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, thresholds, initial_active, steps, default_threshold=0.5), name = "threshold_contagion")]
+pub fn threshold_contagion(
+    hypergraph: &Hypergraph,
+    thresholds: HashMap<usize, f64>,
+    initial_active: Vec<usize>,
+    steps: usize,
+    default_threshold: f64,
+) -> PyResult<Vec<Vec<usize>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    threshold_contagion_rust(hypergraph_rust, thresholds, default_threshold, initial_active, steps).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error simulating threshold contagion: {}", e))
+    })
+}
+
+/// Python wrapper for computing the stationary distribution of the random walk on a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - Limiting visit probability of each node
+/// * Raises `PyValueError` if the hypergraph is not connected or the iteration does not converge
+#[pyfunction]
+#[pyo3(signature = (hypergraph, tol=1e-10, max_iter=1000), name = "stationary_distribution")]
+pub fn stationary_distribution(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyResult<HashMap<usize, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    stationary_distribution_rust(hypergraph_rust, tol, max_iter).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing stationary distribution: {}", e))
+    })
+}
+
+/// Python wrapper for computing the personalized PageRank of nodes in a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `restart_weights` - Restart weight of each seed node, normalized if it does not sum to 1
+/// * `damping` - Probability of following an edge instead of restarting
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - PageRank score of each node
+/// * Raises `PyValueError` if the arguments are invalid or the iteration does not converge
+#[pyfunction]
+#[pyo3(signature = (hypergraph, restart_weights, damping=0.85, tol=1e-10, max_iter=1000), name = "personalized_pagerank")]
+pub fn personalized_pagerank(
+    hypergraph: &Hypergraph,
+    restart_weights: HashMap<usize, f64>,
+    damping: f64,
+    tol: f64,
+    max_iter: usize,
+) -> PyResult<HashMap<usize, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    personalized_pagerank_rust(hypergraph_rust, &restart_weights, damping, tol, max_iter).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing personalized PageRank: {}", e))
+    })
+}
+
+/// Python wrapper for computing the mean first-passage time of the random walk between two nodes.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `source` - Node the walk starts from
+/// * `target` - Node the walk has to reach
+/// 
+/// # Returns
+/// * `PyResult<f64>` - Expected number of steps to reach `target` from `source`
+/// * Raises `PyValueError` if a node is missing or `target` is unreachable
+#[pyfunction]
+#[pyo3(signature = (hypergraph, source, target), name = "hitting_time")]
+pub fn hitting_time(hypergraph: &Hypergraph, source: usize, target: usize) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    hitting_time_rust(hypergraph_rust, source, target).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing hitting time: {}", e))
+    })
+}
+
+/// Python wrapper for generating a corpus of random walks on a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `num_walks` - Number of walks started from each node
+/// * `walk_length` - Number of nodes in each walk, including the starting node
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<usize>>>` - The walks, as lists of node IDs
+/// * Raises `PyValueError` if the hypergraph is empty or `walk_length` is 0
+#[pyfunction]
+#[pyo3(signature = (hypergraph, num_walks, walk_length, seed=None), name = "walk_corpus")]
+pub fn walk_corpus(hypergraph: &Hypergraph, num_walks: usize, walk_length: usize, seed: Option<u64>) -> PyResult<Vec<Vec<usize>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    walk_corpus_rust(hypergraph_rust, num_walks, walk_length, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error generating walk corpus: {}", e))
+    })
+}
@@ -1 +1,3 @@
+pub mod contagion;
+pub mod dynamics_wrapp;
 pub mod randwalk;
\ No newline at end of file
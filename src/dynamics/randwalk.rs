@@ -1,98 +1,272 @@
-// use numpy::ndarray::{Array2, Axis};
-// use pyo3::prelude::*;
-// use pyo3::types::PyList;
-// use sprs::CsMat;
-// use crate::core::hypergraph::Hypergraph;
-// use rand::Rng;
-
-// fn transition_matrix(py: Python, hg: &Hypergraph) -> PyResult<CsMat<f64>> {
-//     let n = hg.num_nodes();
-//     let mut t = Array2::<f64>::zeros((n, n));
-    
-//     let edges_py = hg.get_edges(py, false, None, None, false, false, false)?;
-//     let edges_list: &PyList = edges_py.extract(py)?;
-
-//     for edge in edges_list.iter() {
-//         let edge_list: &PyList = edge.extract()?;  
-//         let edge_len = edge_list.len();
-//         let edge_vec: Vec<usize> = edge_list.extract()?;  
-    
-//         for i in 0..edge_len {
-//             for j in (i + 1)..edge_len {
-//                 let u = edge_vec[i];
-//                 let v = edge_vec[j];
-//                 t[(u, v)] += (edge_len - 1) as f64;
-//                 t[(v, u)] += (edge_len - 1) as f64;
-//             }
-//         }
-//     }
-   
-//     let row_sums = t.sum_axis(Axis(1));
-//     for (i, row_sum) in row_sums.iter().enumerate() {
-//         if *row_sum > 0.0 {
-//             t.row_mut(i).mapv_inplace(|x| x / row_sum);
-//         }
-//     }
-    
-//     let mut row_ptrs = Vec::with_capacity(n + 1);
-//     let mut col_indices = Vec::new();
-//     let mut values = Vec::new();
-    
-//     row_ptrs.push(0);
-//     let mut current_index = 0;
-
-//     for i in 0..n {
-//         for j in 0..n {
-//             if t[(i, j)] != 0.0 {
-//                 col_indices.push(j);
-//                 values.push(t[(i, j)]);
-//                 current_index += 1;
-//             }
-//         }
-//         row_ptrs.push(current_index);
-//     }
-
-//     let csr_t = CsMat::new(
-//         (n, n),
-//         row_ptrs,
-//         col_indices,
-//         values,
-//     );
-
-//     Ok(csr_t)
-// }
-
-// #[pyfunction]
-// pub fn random_walk(py: Python, hg: &Hypergraph, s: usize, time: usize) -> PyResult<Vec<usize>> {
-//     let k = transition_matrix(py, hg)?;
-
-//     let mut nodes = vec![s];
-//     let mut rng = rand::thread_rng();
-
-//     for _ in 0..time {
-//         let current_node = *nodes.last().unwrap();
-//         let row = k.outer_view(current_node).unwrap();
-//         let probs: Vec<f64> = row.iter().map(|(_, &val)| val).collect();
-
-//         if probs.is_empty() {
-//             break;
-//         }
-
-//         let cumulative_probs: Vec<f64> = probs.iter().scan(0.0, |acc, &p| {
-//             *acc += p;
-//             Some(*acc)
-//         }).collect();
-
-//         let rand_num = rng.gen::<f64>();
-//         let next_node = row.indices()
-//                            .iter()
-//                            .zip(cumulative_probs.iter())
-//                            .find(|&(_, &cumulative_prob)| rand_num < cumulative_prob)
-//                            .map(|(&index, _)| index)
-//                            .unwrap_or(current_node);
-
-//         nodes.push(next_node);
-//     }
-
-//     Ok(nodes)
-// }
+extern crate nalgebra as na;
+use na::{DMatrix, DVector, RowDVector};
+use rand::distributions::{Distribution, WeightedIndex};
+use std::collections::HashMap;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+use crate::core::label_encoder::LabelEncoder;
+use crate::core::rng::rng_from_seed;
+
+/// Builds the transition matrix of the random walk on a hypergraph.
+///
+/// From node `u` the walker moves to `v` with probability proportional to the sum, over the
+/// edges `e` containing both, of `w(e) * (|e| - 1)`, so larger and heavier hyperedges are
+/// crossed more often. Rows of isolated nodes are left at zero.
+///
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+///
+/// # Returns
+/// * `Ok((LabelEncoder, DMatrix<f64>))` - The encoder mapping node IDs to row indices, and the
+///   row-stochastic transition matrix
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn transition_matrix_rust(hypergraph: &HypergraphRust) -> Result<(LabelEncoder, DMatrix<f64>), HypergraphError> {
+    let encoder = hypergraph.get_mapping()?;
+    let mapping = encoder.get_mapping();
+    let num_nodes = mapping.len();
+
+    let mut transition = DMatrix::<f64>::zeros(num_nodes, num_nodes);
+    for (edge, &weight) in &hypergraph.edge_list {
+        let strength = weight * (edge.len() - 1) as f64;
+        for &u in edge {
+            for &v in edge {
+                if u != v {
+                    transition[(mapping[&u], mapping[&v])] += strength;
+                }
+            }
+        }
+    }
+
+    for mut row in transition.row_iter_mut() {
+        let total = row.sum();
+        if total > 0.0 {
+            row /= total;
+        }
+    }
+
+    Ok((encoder, transition))
+}
+
+/// Computes the stationary distribution of the random walk on a hypergraph.
+///
+/// The walk is the weighted one of `transition_matrix_rust`: edges are crossed with probability
+/// proportional to `w(e) * (|e| - 1)`, so on unweighted hypergraphs the multiplicities act as
+/// weights. The distribution is the left eigenvector of the transition matrix for eigenvalue 1,
+/// found by power iteration from the uniform distribution.
+///
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `tol` - Tolerance on the L1 change between two iterates
+/// * `max_iter` - Maximum number of iterations
+///
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - Limiting visit probability of each node
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty or not connected, or if the iteration
+///   does not converge within `max_iter` steps (e.g. on a periodic walk)
+pub fn stationary_distribution_rust(
+    hypergraph: &HypergraphRust,
+    tol: f64,
+    max_iter: usize,
+) -> Result<HashMap<usize, f64>, HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
+    if !hypergraph.is_connected_rust() {
+        return Err(HypergraphError::NotConnected.with_message(
+            "The hypergraph is not connected (isolated nodes count as components, see remove_isolated_nodes)."
+        ));
+    }
+
+    let (encoder, transition) = transition_matrix_rust(hypergraph)?;
+    let num_nodes = transition.nrows();
+
+    let mut distribution = RowDVector::from_element(num_nodes, 1.0 / num_nodes as f64);
+    for _ in 0..max_iter {
+        let next = &distribution * &transition;
+        let residual = (&next - &distribution).lp_norm(1);
+        distribution = next;
+        if residual <= tol {
+            return Ok((0..num_nodes)
+                .filter_map(|idx| encoder.inverse_transform(idx).map(|node| (node, distribution[idx])))
+                .collect());
+        }
+    }
+
+    Err(HypergraphError::NotConverged)
+}
+
+/// Computes the personalized PageRank of the random walk on a hypergraph.
+///
+/// At every step the walker follows the transition matrix with probability `damping`, and
+/// otherwise restarts from a node drawn from `restart_weights`. Walkers on isolated nodes always
+/// restart.
+///
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `restart_weights` - Restart weight of each seed node; normalized to sum to 1, nodes not listed get 0
+/// * `damping` - Probability of following an edge instead of restarting, in `[0, 1]`
+/// * `tol` - Tolerance on the L1 change between two iterates
+/// * `max_iter` - Maximum number of iterations
+///
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - PageRank score of each node
+/// * `Err(HypergraphError)` - Error if a restart node is not in the hypergraph, if the restart
+///   weights are negative or sum to 0, if `damping` is out of range, or if the iteration does not converge
+pub fn personalized_pagerank_rust(
+    hypergraph: &HypergraphRust,
+    restart_weights: &HashMap<usize, f64>,
+    damping: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<HashMap<usize, f64>, HypergraphError> {
+    if !(0.0..=1.0).contains(&damping) {
+        return Err(HypergraphError::InvalidArgument("damping must be a probability in [0, 1].".to_string()));
+    }
+    if let Some(&node) = restart_weights.keys().find(|&&node| !hypergraph.check_node(node)) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+    let total: f64 = restart_weights.values().sum();
+    if restart_weights.values().any(|&weight| weight < 0.0) || total <= 0.0 {
+        return Err(HypergraphError::InvalidArgument(
+            "restart weights must be non-negative with a positive sum.".to_string(),
+        ));
+    }
+
+    let (encoder, transition) = transition_matrix_rust(hypergraph)?;
+    let num_nodes = transition.nrows();
+
+    let mut restart = RowDVector::zeros(num_nodes);
+    for (node, &weight) in restart_weights {
+        if let Some(idx) = encoder.transform(*node) {
+            restart[idx] = weight / total;
+        }
+    }
+    // I nodi isolati hanno righe nulle: la loro massa riparte dal vettore di restart
+    let dangling: Vec<usize> = (0..num_nodes).filter(|&idx| transition.row(idx).sum() == 0.0).collect();
+
+    let mut scores = restart.clone();
+    for _ in 0..max_iter {
+        let dangling_mass: f64 = dangling.iter().map(|&idx| scores[idx]).sum();
+        let next = (&scores * &transition + &restart * dangling_mass) * damping + &restart * (1.0 - damping);
+        let residual = (&next - &scores).lp_norm(1);
+        scores = next;
+        if residual <= tol {
+            return Ok((0..num_nodes)
+                .filter_map(|idx| encoder.inverse_transform(idx).map(|node| (node, scores[idx])))
+                .collect());
+        }
+    }
+
+    Err(HypergraphError::NotConverged)
+}
+
+/// Computes the mean first-passage time of the random walk between two nodes.
+///
+/// The hitting times `h(u)` to `target` solve `h(target) = 0` and `h(u) = 1 + sum_v P(u, v) h(v)`
+/// for every other node `u` that can reach `target`; the system is restricted to those nodes and
+/// solved with an LU decomposition.
+///
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `source` - Node the walk starts from
+/// * `target` - Node the walk has to reach
+///
+/// # Returns
+/// * `Ok(f64)` - Expected number of steps to reach `target` from `source`, 0 if they coincide
+/// * `Err(HypergraphError)` - Error if either node is not in the hypergraph or `target` is not
+///   reachable from `source`
+pub fn hitting_time_rust(hypergraph: &HypergraphRust, source: usize, target: usize) -> Result<f64, HypergraphError> {
+    if !hypergraph.check_node(target) {
+        return Err(HypergraphError::NodeNotFound(target));
+    }
+    let reachable = hypergraph.shortest_path_lengths(source)?;
+    if !reachable.contains_key(&target) {
+        return Err(HypergraphError::InvalidArgument(format!(
+            "Node {} is not reachable from node {}.", target, source
+        )));
+    }
+    if source == target {
+        return Ok(0.0);
+    }
+
+    let (encoder, transition) = transition_matrix_rust(hypergraph)?;
+
+    // Il sistema coinvolge solo i nodi della componente di source, escluso target
+    let mut component: Vec<usize> = reachable.into_keys().filter(|&node| node != target).collect();
+    component.sort_unstable();
+    let rows: Vec<usize> = component
+        .iter()
+        .filter_map(|&node| encoder.transform(node))
+        .collect();
+    let size = rows.len();
+
+    let system = DMatrix::from_fn(size, size, |i, j| {
+        let identity = if i == j { 1.0 } else { 0.0 };
+        identity - transition[(rows[i], rows[j])]
+    });
+    let times = system
+        .lu()
+        .solve(&DVector::from_element(size, 1.0))
+        .ok_or_else(|| HypergraphError::InvalidArgument("The hitting-time system is singular.".to_string()))?;
+
+    let position = component.binary_search(&source).map_err(|_| HypergraphError::NodeNotFound(source))?;
+    Ok(times[position])
+}
+
+/// Generates a corpus of random walks on a hypergraph, as input for skip-gram node embeddings.
+///
+/// Every round starts one walk from each node, in ascending node order, and each step follows the
+/// transition matrix of `transition_matrix_rust`. A walk stops early when it reaches an isolated node.
+///
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `num_walks` - Number of walks started from each node
+/// * `walk_length` - Number of nodes in each walk, including the starting node
+/// * `seed` - Optional seed for the random number generator
+///
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - The `num_walks * num_nodes` walks, as sequences of node IDs
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes or `walk_length` is 0
+pub fn walk_corpus_rust(
+    hypergraph: &HypergraphRust,
+    num_walks: usize,
+    walk_length: usize,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<usize>>, HypergraphError> {
+    if walk_length == 0 {
+        return Err(HypergraphError::InvalidArgument("walk_length must be at least 1.".to_string()));
+    }
+
+    let (encoder, transition) = transition_matrix_rust(hypergraph)?;
+    let num_nodes = transition.nrows();
+
+    // Per ogni riga si tengono solo le transizioni non nulle, con la relativa distribuzione
+    let steps: Vec<Option<(Vec<usize>, WeightedIndex<f64>)>> = transition
+        .row_iter()
+        .map(|row| {
+            let targets: Vec<usize> = (0..num_nodes).filter(|&j| row[j] > 0.0).collect();
+            let distribution = WeightedIndex::new(targets.iter().map(|&j| row[j])).ok()?;
+            Some((targets, distribution))
+        })
+        .collect();
+
+    let mut rng = rng_from_seed(seed);
+    let mut corpus = Vec::with_capacity(num_walks * num_nodes);
+    for _ in 0..num_walks {
+        for start in 0..num_nodes {
+            let mut walk = Vec::with_capacity(walk_length);
+            let mut current = start;
+            walk.push(current);
+            while walk.len() < walk_length {
+                let Some((targets, distribution)) = &steps[current] else {
+                    break;
+                };
+                current = targets[distribution.sample(&mut rng)];
+                walk.push(current);
+            }
+            corpus.push(walk.into_iter().filter_map(|idx| encoder.inverse_transform(idx)).collect());
+        }
+    }
+
+    Ok(corpus)
+}
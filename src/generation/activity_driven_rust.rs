@@ -0,0 +1,69 @@
+use std::collections::{BTreeMap, HashMap};
+use rand::seq::index::sample;
+use rand::Rng;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+use crate::core::rng::rng_from_seed;
+
+/// Generates a higher-order activity-driven (HOAD) hypergraph, aggregated over time.
+/// 
+/// At every time step, for each order, node `i` becomes active with probability given by its activity
+/// for that order and, if active, forms an edge with `order` other nodes drawn uniformly at random.
+/// Draws that include the active node itself are discarded.
+/// 
+/// # Arguments
+/// * `num_nodes` - Number of nodes, labelled from `0` to `num_nodes - 1`
+/// * `activities_per_order` - Activity of every node for each edge order
+/// * `time` - Number of time steps to simulate
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `Result<HypergraphRust, HypergraphError>` - A weighted hypergraph whose weights count the time steps in which
+///   each edge was formed, or an error message if the activities are invalid
+pub fn hoad_model_rust(
+    num_nodes: usize,
+    activities_per_order: HashMap<usize, Vec<f64>>,
+    time: usize,
+    seed: Option<u64>,
+) -> Result<HypergraphRust, HypergraphError> {
+    let mut rng = rng_from_seed(seed);
+
+    // Iteriamo gli ordini in ordine per avere risultati riproducibili con lo stesso seed
+    let by_order: BTreeMap<usize, Vec<f64>> = activities_per_order.into_iter().collect();
+    for (&order, activities) in &by_order {
+        if order == 0 || order > num_nodes {
+            return Err(HypergraphError::InvalidArgument(format!("Invalid order {} for a hypergraph with {} nodes.", order, num_nodes)));
+        }
+        if activities.len() != num_nodes {
+            return Err(HypergraphError::InvalidArgument(format!(
+                "Expected {} activities for order {}, got {}.",
+                num_nodes, order, activities.len()
+            )));
+        }
+    }
+
+    let mut counts: BTreeMap<Vec<usize>, f64> = BTreeMap::new();
+    for (order, activities) in &by_order {
+        for _ in 0..time {
+            for (node, &activity) in activities.iter().enumerate() {
+                if activity > rng.gen::<f64>() {
+                    let mut edge = sample(&mut rng, num_nodes, *order).into_vec();
+                    if edge.contains(&node) {
+                        continue;
+                    }
+                    edge.push(node);
+                    edge.sort_unstable();
+                    *counts.entry(edge).or_insert(0.0) += 1.0;
+                }
+            }
+        }
+    }
+
+    let mut hypergraph = HypergraphRust::empty(true);
+    hypergraph.add_nodes((0..num_nodes).collect());
+    for (edge, count) in counts {
+        hypergraph.add_edge(edge, Some(count), None)?;
+    }
+
+    Ok(hypergraph)
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use crate::core::hypergraph_wrapp::Hypergraph;
+use super::activity_driven_rust::*;
+use super::random_rust::*;
+
+/// Python wrapper for sampling edges proportionally to their weight, without replacement.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `k` - Number of edges to draw
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<usize>>>` - The sampled edges, in the order in which they were drawn
+#[pyfunction]
+#[pyo3(signature = (hypergraph, k, seed=None), name = "sample_edges")]
+pub fn sample_edges(hypergraph: &Hypergraph, k: usize, seed: Option<u64>) -> PyResult<Vec<Vec<usize>>> {
+    Ok(hypergraph.inner.sample_edges(k, seed))
+}
+
+/// Python wrapper for generating a random hypergraph with a fixed number of edges per size.
+/// 
+/// # Arguments
+/// * `num_nodes` - Number of nodes, labelled from `0` to `num_nodes - 1`
+/// * `num_edges_by_size` - Number of distinct edges to draw for each edge size
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Hypergraph>` - The generated unweighted hypergraph
+/// * Raises `PyValueError` if the generation fails
+#[pyfunction]
+#[pyo3(signature = (num_nodes, num_edges_by_size, seed=None), name = "random_hypergraph")]
+pub fn random_hypergraph(
+    num_nodes: usize,
+    num_edges_by_size: HashMap<usize, usize>,
+    seed: Option<u64>,
+) -> PyResult<Hypergraph> {
+    let hypergraph = random_hypergraph_rust(num_nodes, num_edges_by_size, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error generating random hypergraph: {}", e))
+    })?;
+    Ok(Hypergraph { inner: hypergraph })
+}
+
+/// Python wrapper for generating a uniform hypergraph with a prescribed degree sequence.
+/// 
+/// # Arguments
+/// * `degree_sequence` - Target degree of each node, where node `i` has degree `degree_sequence[i]`
+/// * `edge_size` - Size of every generated edge
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Hypergraph>` - The generated unweighted hypergraph
+/// * Raises `PyValueError` if the input is invalid or no matching is found
+#[pyfunction]
+#[pyo3(signature = (degree_sequence, edge_size, seed=None), name = "configuration_model")]
+pub fn configuration_model(
+    degree_sequence: Vec<usize>,
+    edge_size: usize,
+    seed: Option<u64>,
+) -> PyResult<Hypergraph> {
+    let hypergraph = configuration_model_rust(degree_sequence, edge_size, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error generating configuration model: {}", e))
+    })?;
+    Ok(Hypergraph { inner: hypergraph })
+}
+
+/// Python wrapper for generating a Chung-Lu uniform hypergraph with prescribed expected degrees.
+/// 
+/// # Arguments
+/// * `expected_degrees` - Expected degree of each node, where node `i` has weight `expected_degrees[i]`
+/// * `num_edges` - Number of edges to draw
+/// * `edge_size` - Size of every generated edge
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Hypergraph>` - The generated unweighted hypergraph, with repeated draws stored as multiplicities
+/// * Raises `PyValueError` if the input is invalid
+#[pyfunction]
+#[pyo3(signature = (expected_degrees, num_edges, edge_size, seed=None), name = "chung_lu")]
+pub fn chung_lu(
+    expected_degrees: Vec<f64>,
+    num_edges: usize,
+    edge_size: usize,
+    seed: Option<u64>,
+) -> PyResult<Hypergraph> {
+    let hypergraph = chung_lu_rust(expected_degrees, num_edges, edge_size, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error generating Chung-Lu model: {}", e))
+    })?;
+    Ok(Hypergraph { inner: hypergraph })
+}
+
+/// Python wrapper for generating a higher-order activity-driven hypergraph, aggregated over time.
+/// 
+/// # Arguments
+/// * `num_nodes` - Number of nodes, labelled from `0` to `num_nodes - 1`
+/// * `activities_per_order` - Activity of every node for each edge order
+/// * `time` - Number of time steps to simulate
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `PyResult<Hypergraph>` - A weighted hypergraph whose weights count how often each edge was formed
+/// * Raises `PyValueError` if the activities are invalid
+#[pyfunction]
+#[pyo3(signature = (num_nodes, activities_per_order, time=100, seed=None), name = "hoad_model")]
+pub fn hoad_model(
+    num_nodes: usize,
+    activities_per_order: HashMap<usize, Vec<f64>>,
+    time: usize,
+    seed: Option<u64>,
+) -> PyResult<Hypergraph> {
+    let hypergraph = hoad_model_rust(num_nodes, activities_per_order, time, seed).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error generating HOAD model: {}", e))
+    })?;
+    Ok(Hypergraph { inner: hypergraph })
+}
@@ -0,0 +1,3 @@
+pub mod activity_driven_rust;
+pub mod generation_wrapp;
+pub mod random_rust;
@@ -0,0 +1,215 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use rand::rngs::StdRng;
+use rand::seq::index::{sample, sample_weighted};
+use rand::Rng;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+use crate::core::rng::rng_from_seed;
+
+/// Returns the number of distinct edges of a given size on `num_nodes` nodes, saturating at `usize::MAX`.
+fn max_edges(num_nodes: usize, size: usize) -> usize {
+    let k = size.min(num_nodes - size);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (num_nodes - i) as u128 / (i + 1) as u128;
+        if result > usize::MAX as u128 {
+            return usize::MAX;
+        }
+    }
+    result as usize
+}
+
+/// Generates an Erdős–Rényi-like random hypergraph with a fixed number of edges per size.
+/// 
+/// # Arguments
+/// * `num_nodes` - Number of nodes, labelled from `0` to `num_nodes - 1`
+/// * `num_edges_by_size` - Number of distinct edges to draw for each edge size
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `Result<HypergraphRust, HypergraphError>` - An unweighted hypergraph containing every node, or an error message
+///   if a size is invalid or more edges are requested than exist for that size
+pub fn random_hypergraph_rust(
+    num_nodes: usize,
+    num_edges_by_size: HashMap<usize, usize>,
+    seed: Option<u64>,
+) -> Result<HypergraphRust, HypergraphError> {
+    let mut rng = rng_from_seed(seed);
+
+    // Iteriamo le dimensioni in ordine per avere risultati riproducibili con lo stesso seed
+    let by_size: BTreeMap<usize, usize> = num_edges_by_size.into_iter().collect();
+    for (&size, &count) in &by_size {
+        if size < 2 || size > num_nodes {
+            return Err(HypergraphError::InvalidArgument(format!("Invalid edge size {} for a hypergraph with {} nodes.", size, num_nodes)));
+        }
+        if count > max_edges(num_nodes, size) {
+            return Err(HypergraphError::InvalidArgument(format!("Cannot draw {} distinct edges of size {} on {} nodes.", count, size, num_nodes)));
+        }
+    }
+
+    let mut hypergraph = HypergraphRust::empty(false);
+    hypergraph.add_nodes((0..num_nodes).collect());
+
+    for (size, count) in by_size {
+        let mut drawn: HashSet<Vec<usize>> = HashSet::with_capacity(count);
+        while drawn.len() < count {
+            let mut edge = sample(&mut rng, num_nodes, size).into_vec();
+            edge.sort_unstable();
+            if drawn.insert(edge.clone()) {
+                hypergraph.add_edge(edge, None, None)?;
+            }
+        }
+    }
+
+    Ok(hypergraph)
+}
+
+/// Number of times the stub matching is restarted before giving up.
+const MAX_MATCHING_ATTEMPTS: usize = 100;
+
+/// Pairs the stubs into edges of `edge_size` distinct nodes, returning `None` if the matching gets stuck
+/// or produces a repeated edge.
+fn match_stubs(stubs: &[usize], edge_size: usize, rng: &mut StdRng) -> Option<Vec<Vec<usize>>> {
+    let mut remaining = stubs.to_vec();
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    let mut edges = Vec::with_capacity(stubs.len() / edge_size);
+
+    while !remaining.is_empty() {
+        let mut edge = Vec::with_capacity(edge_size);
+        for _ in 0..edge_size {
+            // Pesca uno stub a caso, scartando quelli di nodi già presenti nell'arco
+            let mut picked = false;
+            for _ in 0..remaining.len().max(10) {
+                let idx = rng.gen_range(0..remaining.len());
+                if !edge.contains(&remaining[idx]) {
+                    edge.push(remaining.swap_remove(idx));
+                    picked = true;
+                    break;
+                }
+            }
+            if !picked {
+                return None;
+            }
+        }
+        edge.sort_unstable();
+        if !seen.insert(edge.clone()) {
+            return None;
+        }
+        edges.push(edge);
+    }
+
+    Some(edges)
+}
+
+/// Generates a random uniform hypergraph with a prescribed degree sequence via stub matching.
+/// 
+/// # Arguments
+/// * `degree_sequence` - Target degree of each node, where node `i` has degree `degree_sequence[i]`
+/// * `edge_size` - Size of every generated edge
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `Result<HypergraphRust, HypergraphError>` - An unweighted hypergraph without repeated edges whose node degrees
+///   match `degree_sequence`, or an error message if the input is invalid or no matching is found
+/// 
+/// # Notes
+/// Matchings that place a node twice in the same edge or repeat an edge are rejected and the matching is
+/// restarted, up to a fixed number of attempts.
+pub fn configuration_model_rust(
+    degree_sequence: Vec<usize>,
+    edge_size: usize,
+    seed: Option<u64>,
+) -> Result<HypergraphRust, HypergraphError> {
+    if edge_size < 2 {
+        return Err(HypergraphError::InvalidArgument("The edge size must be at least 2.".to_string()));
+    }
+    let total_stubs: usize = degree_sequence.iter().sum();
+    if !total_stubs.is_multiple_of(edge_size) {
+        return Err(HypergraphError::InvalidArgument(format!(
+            "The sum of the degrees ({}) is not divisible by the edge size ({}).",
+            total_stubs, edge_size
+        )));
+    }
+    let num_active = degree_sequence.iter().filter(|&&d| d > 0).count();
+    if total_stubs > 0 && num_active < edge_size {
+        return Err(HypergraphError::InvalidArgument(format!(
+            "At least {} nodes with positive degree are needed to build edges of size {}.",
+            edge_size, edge_size
+        )));
+    }
+
+    let mut rng = rng_from_seed(seed);
+
+    let stubs: Vec<usize> = degree_sequence
+        .iter()
+        .enumerate()
+        .flat_map(|(node, &degree)| std::iter::repeat_n(node, degree))
+        .collect();
+
+    for _ in 0..MAX_MATCHING_ATTEMPTS {
+        if let Some(edges) = match_stubs(&stubs, edge_size, &mut rng) {
+            let mut hypergraph = HypergraphRust::empty(false);
+            hypergraph.add_nodes((0..degree_sequence.len()).collect());
+            for edge in edges {
+                hypergraph.add_edge(edge, None, None)?;
+            }
+            return Ok(hypergraph);
+        }
+    }
+
+    Err(HypergraphError::InvalidArgument(format!(
+        "Could not match the degree sequence into distinct edges after {} attempts.",
+        MAX_MATCHING_ATTEMPTS
+    )))
+}
+
+/// Generates a Chung-Lu random uniform hypergraph, a soft version of the configuration model.
+/// 
+/// Every edge is drawn independently by picking `edge_size` distinct nodes, each with probability
+/// proportional to its expected degree. When `num_edges * edge_size` equals the sum of the expected
+/// degrees, node degrees match `expected_degrees` on average, up to the bias introduced by drawing
+/// distinct nodes within an edge.
+/// 
+/// # Arguments
+/// * `expected_degrees` - Expected degree of each node, where node `i` has weight `expected_degrees[i]`
+/// * `num_edges` - Number of edges to draw
+/// * `edge_size` - Size of every generated edge
+/// * `seed` - Optional seed for the random number generator
+/// 
+/// # Returns
+/// * `Result<HypergraphRust, HypergraphError>` - An unweighted hypergraph containing every node, where an edge
+///   drawn more than once gets a higher multiplicity, or an error message if the input is invalid
+pub fn chung_lu_rust(
+    expected_degrees: Vec<f64>,
+    num_edges: usize,
+    edge_size: usize,
+    seed: Option<u64>,
+) -> Result<HypergraphRust, HypergraphError> {
+    if edge_size < 2 {
+        return Err(HypergraphError::InvalidArgument("The edge size must be at least 2.".to_string()));
+    }
+    if expected_degrees.iter().any(|&degree| !degree.is_finite() || degree < 0.0) {
+        return Err(HypergraphError::InvalidArgument("Expected degrees must be finite and non-negative.".to_string()));
+    }
+    let num_active = expected_degrees.iter().filter(|&&degree| degree > 0.0).count();
+    if num_edges > 0 && num_active < edge_size {
+        return Err(HypergraphError::InvalidArgument(format!(
+            "At least {} nodes with positive expected degree are needed to build edges of size {}.",
+            edge_size, edge_size
+        )));
+    }
+
+    let mut rng = rng_from_seed(seed);
+
+    let mut hypergraph = HypergraphRust::with_capacity(expected_degrees.len(), num_edges, false);
+    hypergraph.add_nodes((0..expected_degrees.len()).collect());
+
+    for _ in 0..num_edges {
+        let edge = sample_weighted(&mut rng, expected_degrees.len(), |node| expected_degrees[node], edge_size)
+            .map_err(|e| HypergraphError::InvalidArgument(e.to_string()))?
+            .into_vec();
+        hypergraph.add_edge(edge, None, None)?;
+    }
+
+    Ok(hypergraph)
+}
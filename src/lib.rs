@@ -1,23 +1,61 @@
 use pyo3::prelude::*;
 mod core;
 mod dynamics;
+mod generation;
 mod measures;
 
 #[pymodule]
 fn rusthypergraph(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    // m.add_class::<core::hypergraph::Hypergraph>()?;
     m.add_class::<core::hypergraph_wrapp::Hypergraph>()?;
+    m.add_class::<core::temporal_hypergraph_wrapp::TemporalHypergraph>()?;
+    m.add_class::<core::directed_hypergraph_wrapp::DirectedHypergraph>()?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_sequence))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_correlation))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_distribution))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_distribution_normalized))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_distribution_cdf))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_entropy))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::degree_mixing_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::average_neighbor_degree))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::knn))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::weighted_projection_degree))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::effective_size))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::intersection))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::jaccard_similarity))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::jaccard_distance))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::overlap_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::dice_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::edge_similarity_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::node_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::node_similarity_matrix))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::cec_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::cec_centrality_general_py))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::zec_centrality))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::hec_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::communicability))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::harmonic_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::spectral_embedding))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::conductance))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::adjacency_spectrum))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::laplacian_spectrum))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::top_k))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::normalize_centrality))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::s_betweenness))?;
     m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::s_closeness))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::s_connected_components))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::clique_percolation))?;
+    m.add_wrapped(wrap_pyfunction!(measures::measures_wrapp::line_graph_py))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::sis_contagion))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::threshold_contagion))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::stationary_distribution))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::personalized_pagerank))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::hitting_time))?;
+    m.add_wrapped(wrap_pyfunction!(dynamics::dynamics_wrapp::walk_corpus))?;
+    m.add_wrapped(wrap_pyfunction!(generation::generation_wrapp::sample_edges))?;
+    m.add_wrapped(wrap_pyfunction!(generation::generation_wrapp::random_hypergraph))?;
+    m.add_wrapped(wrap_pyfunction!(generation::generation_wrapp::configuration_model))?;
+    m.add_wrapped(wrap_pyfunction!(generation::generation_wrapp::chung_lu))?;
+    m.add_wrapped(wrap_pyfunction!(generation::generation_wrapp::hoad_model))?;
     Ok(())
 }
\ No newline at end of file
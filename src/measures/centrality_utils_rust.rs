@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use crate::core::error::HypergraphError;
+
+/// Selects the `k` highest-scoring nodes of a centrality map.
+/// 
+/// # Arguments
+/// * `centrality` - Map of node indices to their centrality values
+/// * `k` - Number of nodes to return
+/// 
+/// # Returns
+/// The `k` nodes with the highest scores, sorted by decreasing score and by increasing node index on ties.
+/// Fewer than `k` pairs are returned if the map is smaller.
+pub fn top_k_rust(centrality: &HashMap<usize, f64>, k: usize) -> Vec<(usize, f64)> {
+    let mut scores: Vec<(usize, f64)> = centrality.iter().map(|(&node, &score)| (node, score)).collect();
+    scores.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    scores.truncate(k);
+    scores
+}
+
+/// Rescales a centrality map so that scores from different measures are comparable.
+/// 
+/// # Arguments
+/// * `centrality` - Map of node indices to their centrality values
+/// * `mode` - `"max"` to divide by the largest absolute score, `"l1"` or `"l2"` to obtain a vector with unit norm
+/// 
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - The rescaled centrality map
+/// * `Err(HypergraphError)` - Error if `mode` is unknown or every score is zero
+pub fn normalize_centrality_rust(centrality: &HashMap<usize, f64>, mode: &str) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let norm = match mode {
+        "max" => centrality.values().fold(0.0_f64, |acc, score| acc.max(score.abs())),
+        "l1" => centrality.values().map(|score| score.abs()).sum(),
+        "l2" => centrality.values().map(|score| score * score).sum::<f64>().sqrt(),
+        _ => return Err(HypergraphError::InvalidArgument(format!("Unknown mode '{}', expected 'max', 'l1' or 'l2'.", mode))),
+    };
+
+    if norm == 0.0 {
+        return Err(HypergraphError::InvalidArgument("Cannot normalize a centrality whose scores are all zero.".to_string()));
+    }
+
+    Ok(centrality.iter().map(|(&node, &score)| (node, score / norm)).collect())
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use crate::core::error::HypergraphError;
 use crate::core::hypergraph_rust::HypergraphRust;
 
 /// Calculates the degree of a node in a hypergraph.
@@ -11,11 +12,11 @@ use crate::core::hypergraph_rust::HypergraphRust;
 /// 
 /// # Returns
 /// * `Ok(u64)` - The degree of the node
-/// * `Err(String)` - Error if both order and size are specified
-pub fn degree_rust(hypergraph: &HypergraphRust, node: usize, order: Option<usize>, size: Option<usize>) -> Result<u64, String> {
+/// * `Err(HypergraphError)` - Error if both order and size are specified
+pub fn degree_rust(hypergraph: &HypergraphRust, node: usize, order: Option<usize>, size: Option<usize>) -> Result<u64, HypergraphError> {
     
     let edges = match (order, size) {
-        (Some(_), Some(_)) => return Err("Order and size cannot be both specified.".to_string()),
+        (Some(_), Some(_)) => return Err(HypergraphError::InvalidArgument("Order and size cannot be both specified.".to_string())),
         (Some(order), None) => hypergraph.get_incident_edges(node, Some(order), None)?,
         (None, Some(size)) => hypergraph.get_incident_edges(node, None, Some(size))?,
         (None, None) => hypergraph.get_incident_edges(node, None, None)?,
@@ -33,28 +34,47 @@ pub fn degree_rust(hypergraph: &HypergraphRust, node: usize, order: Option<usize
 /// 
 /// # Returns
 /// * `Ok(Some(HashMap<usize, u64>))` - Map of node indices to their degrees
-/// * `Err(String)` - Error if both order and size are specified
-pub fn degree_sequence_rust(hypergraph: &HypergraphRust, order: Option<usize>, size: Option<usize>) -> Result<Option<HashMap<usize, u64>>, String> {
+/// * `Err(HypergraphError)` - Error if both order and size are specified
+/// 
+/// # Notes
+/// With the `rayon` feature enabled, the per-node degrees are computed in parallel. The gain depends
+/// on the number of cores; `benches/bench_degree_sequence.py` times both builds on the same input.
+pub fn degree_sequence_rust(hypergraph: &HypergraphRust, order: Option<usize>, size: Option<usize>) -> Result<Option<HashMap<usize, u64>>, HypergraphError> {
     
     if order.is_some() && size.is_some() {
-        return Err("Order and size cannot be both specified.".to_string());
+        return Err(HypergraphError::InvalidArgument("Order and size cannot be both specified.".to_string()));
     }
 
     let order = match size {
         Some(size) if size > 0 => Some(size - 1),
         _ => order,
     };
-    
-    let mut degree_seq = HashMap::new();
-    for node in hypergraph.get_nodes_without_metadata() {
-        let degree = match order {
-            Some(o) => degree_rust(hypergraph, node, Some(o),None)?,
-            None => degree_rust(hypergraph, node, None, None)?,
-        };
 
-        degree_seq.insert(node,degree);
+    #[cfg(feature = "rayon")]
+    let degree_seq = {
+        use rayon::prelude::*;
+
+        hypergraph.get_nodes_without_metadata()
+            .into_par_iter()
+            .map(|node| degree_rust(hypergraph, node, order, None).map(|degree| (node, degree)))
+            .collect::<Result<HashMap<usize, u64>, HypergraphError>>()?
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let degree_seq = {
+        let mut degree_seq = HashMap::new();
+        for node in hypergraph.get_nodes_without_metadata() {
+            let degree = match order {
+                Some(o) => degree_rust(hypergraph, node, Some(o),None)?,
+                None => degree_rust(hypergraph, node, None, None)?,
+            };
+
+            degree_seq.insert(node,degree);
+
+        }
+        degree_seq
+    };
 
-    }
     Ok(Some(degree_seq))
 }
 
@@ -66,10 +86,10 @@ pub fn degree_sequence_rust(hypergraph: &HypergraphRust, order: Option<usize>, s
 /// 
 /// # Returns
 /// * `Ok(f64)` - The correlation coefficient between -1.0 and 1.0
-/// * `Err(String)` - Error if vectors have different lengths or fewer than 2 elements
-pub fn pearson_correlation(x: &[u64], y: &[u64]) -> Result<f64, String> {
+/// * `Err(HypergraphError)` - Error if vectors have different lengths or fewer than 2 elements
+pub fn pearson_correlation(x: &[u64], y: &[u64]) -> Result<f64, HypergraphError> {
     if x.len() != y.len() || x.len() < 2 {
-        return Err("Vectors must have the same length and contain at least two elements.".to_string());
+        return Err(HypergraphError::InvalidArgument("Vectors must have the same length and contain at least two elements.".to_string()));
     }
 
     let mean_x = x.iter().map(|&xi| xi as f64).sum::<f64>() / x.len() as f64;
@@ -98,15 +118,15 @@ pub fn pearson_correlation(x: &[u64], y: &[u64]) -> Result<f64, String> {
 /// 
 /// # Returns
 /// * `Ok(Vec<Vec<f64>>)` - Matrix of correlation coefficients
-/// * `Err(String)` - Error if degree sequences cannot be computed
-pub fn degree_correlation_rust(hypergraph: &HypergraphRust) -> Result<Vec<Vec<f64>>, String> {
+/// * `Err(HypergraphError)` - Error if degree sequences cannot be computed
+pub fn degree_correlation_rust(hypergraph: &HypergraphRust) -> Result<Vec<Vec<f64>>, HypergraphError> {
     let max_size = hypergraph.max_size();
     let mut seqs = Vec::new();
 
     for size in 2..=max_size {
         match degree_sequence_rust(hypergraph, None, Some(size))? {
             Some(seq) => seqs.push(seq),
-            None => return Err(format!("Failed to get degree sequence for size {}", size)),
+            None => return Err(HypergraphError::InvalidArgument(format!("Failed to get degree sequence for size {}", size))),
         }
     }
 
@@ -145,14 +165,14 @@ pub fn degree_correlation_rust(hypergraph: &HypergraphRust) -> Result<Vec<Vec<f6
 /// 
 /// # Returns
 /// * `Ok(Some(HashMap<u64, i32>))` - Map of degrees to their frequencies
-/// * `Err(String)` - Error if both order and size are specified
+/// * `Err(HypergraphError)` - Error if both order and size are specified
 pub fn degree_distribution_rust(
     hypergraph: &HypergraphRust,
     order: Option<usize>,
     size: Option<usize>,
-) -> Result<Option<HashMap<u64, i32>>, String> {
+) -> Result<Option<HashMap<u64, i32>>, HypergraphError> {
     if order.is_some() && size.is_some() {
-        return Err("Order and size cannot be both specified.".to_string());
+        return Err(HypergraphError::InvalidArgument("Order and size cannot be both specified.".to_string()));
     }
 
     let effective_order = if let Some(s) = size {
@@ -173,3 +193,249 @@ pub fn degree_distribution_rust(
 
     Ok(Some(degree_dist))
 }
+
+/// Calculates the degree distribution of a hypergraph as probabilities.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `Ok(HashMap<u64, f64>)` - Map of degrees to the fraction of nodes with that degree
+/// * `Err(HypergraphError)` - Error if both order and size are specified
+pub fn degree_distribution_normalized_rust(
+    hypergraph: &HypergraphRust,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> Result<HashMap<u64, f64>, HypergraphError> {
+    let degree_dist = degree_distribution_rust(hypergraph, order, size)?.unwrap_or_default();
+    let num_nodes: i32 = degree_dist.values().sum();
+
+    Ok(degree_dist
+        .into_iter()
+        .map(|(degree, count)| (degree, count as f64 / num_nodes as f64))
+        .collect())
+}
+
+/// Calculates the complementary cumulative degree distribution of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `Ok(Vec<(u64, f64)>)` - Pairs `(k, P(degree >= k))` for every observed degree `k`, sorted by degree
+/// * `Err(HypergraphError)` - Error if both order and size are specified
+pub fn degree_distribution_cdf_rust(
+    hypergraph: &HypergraphRust,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> Result<Vec<(u64, f64)>, HypergraphError> {
+    let mut distribution: Vec<(u64, f64)> = degree_distribution_normalized_rust(hypergraph, order, size)?
+        .into_iter()
+        .collect();
+    distribution.sort_unstable_by_key(|&(degree, _)| degree);
+
+    // Sommiamo le probabilità partendo dai gradi più alti
+    let mut cumulative = 0.0;
+    for (_, probability) in distribution.iter_mut().rev() {
+        cumulative += *probability;
+        *probability = cumulative;
+    }
+
+    Ok(distribution)
+}
+
+/// Calculates the Shannon entropy of the degree distribution of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `Ok(f64)` - The entropy (natural logarithm) of the normalized degree distribution, `0.0` if there are no nodes
+/// * `Err(HypergraphError)` - Error if both order and size are specified
+pub fn degree_entropy_rust(
+    hypergraph: &HypergraphRust,
+    order: Option<usize>,
+    size: Option<usize>,
+) -> Result<f64, HypergraphError> {
+    let degree_dist = degree_distribution_rust(hypergraph, order, size)?.unwrap_or_default();
+    let total: i32 = degree_dist.values().sum();
+    if total == 0 {
+        return Ok(0.0);
+    }
+
+    Ok(degree_dist
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total as f64;
+            -p * p.ln()
+        })
+        .sum())
+}
+
+/// Calculates the degree mixing matrix of a hypergraph.
+/// 
+/// Every pair of nodes co-occurring in at least one hyperedge (a link of the two-section)
+/// contributes once in both directions, so the matrix is symmetric and its entries sum to one.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// 
+/// # Returns
+/// * `Ok((Vec<u64>, Vec<Vec<f64>>))` - The sorted degree values and the joint distribution of the
+///   degrees at the two ends of a pair, indexed like the degree values. Both are empty if no two nodes co-occur
+/// * `Err(HypergraphError)` - Error if the degrees cannot be computed
+pub fn degree_mixing_matrix_rust(hypergraph: &HypergraphRust) -> Result<(Vec<u64>, Vec<Vec<f64>>), HypergraphError> {
+    let degrees = degree_sequence_rust(hypergraph, None, None)?.unwrap_or_default();
+    let pairs: Vec<(u64, u64)> = hypergraph
+        .two_section()
+        .into_keys()
+        .map(|(u, v)| (degrees[&u], degrees[&v]))
+        .collect();
+
+    let mut values: Vec<u64> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+    values.sort_unstable();
+    values.dedup();
+    let index: HashMap<u64, usize> = values.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+
+    let mut matrix = vec![vec![0.0; values.len()]; values.len()];
+    for &(a, b) in &pairs {
+        matrix[index[&a]][index[&b]] += 1.0;
+        matrix[index[&b]][index[&a]] += 1.0;
+    }
+
+    let total = 2.0 * pairs.len() as f64;
+    if total > 0.0 {
+        for row in matrix.iter_mut() {
+            for entry in row.iter_mut() {
+                *entry /= total;
+            }
+        }
+    }
+
+    Ok((values, matrix))
+}
+
+/// Calculates the average degree of the neighbors of every node.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// 
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - Map of node indices to the mean degree of their neighbors,
+///   `0.0` for nodes without neighbors
+/// * `Err(HypergraphError)` - Error if the degrees cannot be computed
+pub fn average_neighbor_degree_rust(hypergraph: &HypergraphRust) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let degrees = degree_sequence_rust(hypergraph, None, None)?.unwrap_or_default();
+
+    let mut average = HashMap::with_capacity(degrees.len());
+    for &node in degrees.keys() {
+        let neighbors = hypergraph.get_neighbors(node, None, None)?;
+        let knn = if neighbors.is_empty() {
+            0.0
+        } else {
+            neighbors.iter().map(|neighbor| degrees[neighbor] as f64).sum::<f64>() / neighbors.len() as f64
+        };
+        average.insert(node, knn);
+    }
+
+    Ok(average)
+}
+
+/// Calculates the k-nn function, i.e. the average neighbor degree of the nodes of degree `k`.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// 
+/// # Returns
+/// * `Ok(HashMap<u64, f64>)` - Map from each degree `k` present in the hypergraph to the mean of
+///   `average_neighbor_degree_rust` over the nodes of degree `k`
+/// * `Err(HypergraphError)` - Error if the degrees cannot be computed
+pub fn knn_rust(hypergraph: &HypergraphRust) -> Result<HashMap<u64, f64>, HypergraphError> {
+    let degrees = degree_sequence_rust(hypergraph, None, None)?.unwrap_or_default();
+    let average = average_neighbor_degree_rust(hypergraph)?;
+
+    // Accumula somma e conteggio per ciascun grado
+    let mut bins: HashMap<u64, (f64, usize)> = HashMap::new();
+    for (node, knn) in average {
+        let bin = bins.entry(degrees[&node]).or_insert((0.0, 0));
+        bin.0 += knn;
+        bin.1 += 1;
+    }
+
+    Ok(bins.into_iter().map(|(k, (sum, count))| (k, sum / count as f64)).collect())
+}
+
+/// Calculates Burt's effective size of a node on the two-section of the hypergraph.
+/// 
+/// The strength of the tie between two nodes is the number of edges they share. The effective size
+/// of `node` is `sum_j (1 - sum_q p(node, q) * m(j, q))` over its neighbors `j` and `q`, where
+/// `p(node, q)` is the tie to `q` divided by the total strength of `node` and `m(j, q)` is the tie
+/// between `j` and `q` divided by the strongest tie of `j`. Without repeated ties it reduces to the
+/// number of neighbors minus their average number of ties among themselves.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `node` - Node to compute the effective size for
+/// 
+/// # Returns
+/// * `Ok(f64)` - The effective size, `0.0` for nodes without neighbors
+/// * `Err(HypergraphError)` - Error if the node is not in the hypergraph
+pub fn effective_size_rust(hypergraph: &HypergraphRust, node: usize) -> Result<f64, HypergraphError> {
+    if !hypergraph.check_node(node) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+
+    let neighbors = hypergraph.get_neighbors(node, None, None)?;
+    let ties: Vec<f64> = neighbors.iter().map(|&q| hypergraph.co_occurrence(node, q, false)).collect();
+    let total: f64 = ties.iter().sum();
+
+    let mut size = 0.0;
+    for &j in &neighbors {
+        // Il legame più forte di j normalizza la sua ridondanza
+        let strongest = hypergraph
+            .get_neighbors(j, None, None)?
+            .into_iter()
+            .map(|k| hypergraph.co_occurrence(j, k, false))
+            .fold(0.0, f64::max);
+        let redundancy: f64 = neighbors
+            .iter()
+            .zip(&ties)
+            .filter(|(&q, _)| q != j)
+            .map(|(&q, &tie)| tie / total * hypergraph.co_occurrence(j, q, false) / strongest)
+            .sum();
+        size += 1.0 - redundancy;
+    }
+
+    Ok(size)
+}
+
+/// Calculates the degree of a node in the weighted two-section of the hypergraph.
+/// 
+/// Every edge `e` containing the node links it to `|e| - 1` other nodes with the weight of `e`
+/// (its multiplicity if the hypergraph is unweighted), so the result equals the sum of the
+/// `two_section` weights of the pairs containing the node.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the hypergraph
+/// * `node` - Node to compute the projected degree for
+/// 
+/// # Returns
+/// * `Ok(f64)` - The weighted degree of the node in the clique expansion
+/// * `Err(HypergraphError)` - Error if the node is not in the hypergraph
+pub fn weighted_projection_degree_rust(hypergraph: &HypergraphRust, node: usize) -> Result<f64, HypergraphError> {
+    if !hypergraph.check_node(node) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+
+    Ok(hypergraph
+        .get_incident_edges(node, None, None)?
+        .iter()
+        .map(|edge| hypergraph.edge_list[edge] * (edge.len() - 1) as f64)
+        .sum())
+}
@@ -1,4 +1,9 @@
 use std::collections::HashSet;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+
+/// Sorted list of edges and the symmetric matrix of their pairwise similarities.
+pub type SimilarityMatrix = (Vec<Vec<usize>>, Vec<Vec<f64>>);
 
 /// Computes the intersection size of two hash sets.
 /// 
@@ -46,3 +51,155 @@ pub fn jaccard_distance_rust(a: &HashSet<usize>, b: &HashSet<usize>) -> f64 {
     1.0 - jaccard_similarity_rust(a, b)
 }
 
+/// Computes the overlap (Szymkiewicz-Simpson) coefficient between two hash sets.
+///
+/// The overlap coefficient is defined as the size of the intersection divided by the size of the smaller set.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first hash set.
+/// * `b` - A reference to the second hash set.
+///
+/// # Returns
+///
+/// * The overlap coefficient as a floating-point number, 0 if either set is empty.
+pub fn overlap_coefficient_rust(a: &HashSet<usize>, b: &HashSet<usize>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    intersection_rust(a, b) as f64 / a.len().min(b.len()) as f64
+}
+
+/// Computes the Sorensen-Dice coefficient between two hash sets.
+///
+/// The Dice coefficient is defined as twice the size of the intersection divided by the sum of the sizes of the two sets.
+///
+/// # Arguments
+///
+/// * `a` - A reference to the first hash set.
+/// * `b` - A reference to the second hash set.
+///
+/// # Returns
+///
+/// * The Dice coefficient as a floating-point number, 0 if both sets are empty.
+pub fn dice_coefficient_rust(a: &HashSet<usize>, b: &HashSet<usize>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 0.0;
+    }
+    2.0 * intersection_rust(a, b) as f64 / (a.len() + b.len()) as f64
+}
+
+
+/// Computes the pairwise similarity between all the edges of a hypergraph.
+///
+/// # Arguments
+///
+/// * `hypergraph` - A reference to the hypergraph.
+/// * `metric` - The similarity to use, either `"jaccard"`, `"overlap"` (intersection over the size of the smaller edge) or `"dice"`.
+///
+/// # Returns
+///
+/// * The sorted list of edges and the symmetric matrix whose entry `(i, j)` is the similarity between edges `i` and `j`,
+///   or an error message if `metric` is unknown.
+pub fn edge_similarity_matrix_rust(
+    hypergraph: &HypergraphRust,
+    metric: &str,
+) -> Result<SimilarityMatrix, HypergraphError> {
+    let similarity: fn(&HashSet<usize>, &HashSet<usize>) -> f64 = match metric {
+        "jaccard" => jaccard_similarity_rust,
+        "overlap" => overlap_coefficient_rust,
+        "dice" => dice_coefficient_rust,
+        _ => return Err(HypergraphError::InvalidArgument(format!("Unknown metric '{}', expected 'jaccard', 'overlap' or 'dice'.", metric))),
+    };
+
+    let mut edges: Vec<Vec<usize>> = hypergraph.edge_list.keys().cloned().collect();
+    edges.sort_unstable();
+
+    // Ogni insieme di nodi viene costruito una sola volta
+    let edge_sets: Vec<HashSet<usize>> = edges.iter().map(|edge| edge.iter().copied().collect()).collect();
+
+    let num_edges = edges.len();
+    let mut matrix = vec![vec![1.0; num_edges]; num_edges];
+    for i in 0..num_edges {
+        for j in (i + 1)..num_edges {
+            let value = similarity(&edge_sets[i], &edge_sets[j]);
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+
+    Ok((edges, matrix))
+}
+
+/// Computes the similarity between the incidence sets of two nodes.
+///
+/// # Arguments
+///
+/// * `a` - The IDs of the edges incident to the first node.
+/// * `b` - The IDs of the edges incident to the second node.
+/// * `metric` - The similarity to use, either `"cosine"` or `"jaccard"`.
+///
+/// # Returns
+///
+/// * The similarity as a floating-point number, 0 if either set is empty, or an error message if `metric` is unknown.
+fn incidence_similarity(a: &HashSet<usize>, b: &HashSet<usize>, metric: &str) -> Result<f64, HypergraphError> {
+    let similarity: fn(&HashSet<usize>, &HashSet<usize>) -> f64 = match metric {
+        "cosine" => |a, b| intersection_rust(a, b) as f64 / ((a.len() * b.len()) as f64).sqrt(),
+        "jaccard" => jaccard_similarity_rust,
+        _ => return Err(HypergraphError::InvalidArgument(format!("Unknown metric '{}', expected 'cosine' or 'jaccard'.", metric))),
+    };
+    Ok(if a.is_empty() || b.is_empty() { 0.0 } else { similarity(a, b) })
+}
+
+/// Computes the similarity between two nodes from the edges they belong to.
+///
+/// # Arguments
+///
+/// * `hypergraph` - A reference to the hypergraph.
+/// * `u` - The first node.
+/// * `v` - The second node.
+/// * `metric` - The similarity to use, either `"cosine"` or `"jaccard"`.
+///
+/// # Returns
+///
+/// * The similarity between the incidence vectors of `u` and `v`, 0 if either node is isolated,
+///   or an error message if a node is missing or `metric` is unknown.
+pub fn node_similarity_rust(hypergraph: &HypergraphRust, u: usize, v: usize, metric: &str) -> Result<f64, HypergraphError> {
+    incidence_similarity(hypergraph.get_incidence_set(u)?, hypergraph.get_incidence_set(v)?, metric)
+}
+
+/// Computes the pairwise similarity between all the nodes of a hypergraph.
+///
+/// # Arguments
+///
+/// * `hypergraph` - A reference to the hypergraph.
+/// * `metric` - The similarity to use, either `"cosine"` or `"jaccard"`.
+///
+/// # Returns
+///
+/// * The sorted list of nodes and the symmetric matrix whose entry `(i, j)` is the similarity between nodes `i` and `j`,
+///   or an error message if `metric` is unknown.
+pub fn node_similarity_matrix_rust(
+    hypergraph: &HypergraphRust,
+    metric: &str,
+) -> Result<(Vec<usize>, Vec<Vec<f64>>), HypergraphError> {
+    let mut nodes = hypergraph.get_nodes_without_metadata();
+    nodes.sort_unstable();
+
+    let incidence: Vec<&HashSet<usize>> = nodes
+        .iter()
+        .map(|&node| hypergraph.get_incidence_set(node))
+        .collect::<Result<_, _>>()?;
+
+    let num_nodes = nodes.len();
+    let mut matrix = vec![vec![0.0; num_nodes]; num_nodes];
+    for i in 0..num_nodes {
+        for j in i..num_nodes {
+            let value = incidence_similarity(incidence[i], incidence[j], metric)?;
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+
+    Ok((nodes, matrix))
+}
@@ -1,43 +1,129 @@
 extern crate nalgebra as na;
 use na::{DMatrix, DVector};
 use std::collections::HashMap;
+use crate::core::error::HypergraphError;
 use crate::core::hypergraph_rust::HypergraphRust;
 use rand::{distributions::{Distribution, Uniform}, Rng};
 use std::collections::BTreeMap;
 
-/// Performs power iteration method to find the dominant eigenvector of a matrix.
+/// Convergence diagnostics of an iterative centrality computation.
+#[derive(Debug, Clone, Copy)]
+pub struct ConvergenceInfo {
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Residual between the last two iterates.
+    pub residual: f64,
+    /// Whether the residual dropped below the tolerance within the iteration budget.
+    pub converged: bool,
+}
+
+/// Symmetric sparse matrix in compressed sparse row (CSR) format.
+#[derive(Debug, Clone)]
+struct CsrMatrix {
+    /// Offsets of each row into `col_idx` and `values`.
+    row_ptr: Vec<usize>,
+    /// Column index of every stored entry.
+    col_idx: Vec<usize>,
+    /// Value of every stored entry.
+    values: Vec<f64>,
+}
+
+impl CsrMatrix {
+    /// Builds the weighted two-section adjacency of a hypergraph.
+    /// 
+    /// The entries are those of `HypergraphRust::two_section`: every pair of nodes sharing a
+    /// hyperedge is linked with the sum of the `edge_list` values (weights, or multiplicities in
+    /// unweighted hypergraphs) of the hyperedges containing both. `HypergraphRust::adjacency_matrix`
+    /// is the dense form of the same matrix.
+    /// 
+    /// # Arguments
+    /// * `hypergraph` - The input hypergraph
+    /// * `mapping` - Row index of every node, as given by `HypergraphRust::get_mapping`
+    /// 
+    /// # Returns
+    /// * `CsrMatrix` - The symmetric two-section adjacency
+    fn two_section(hypergraph: &HypergraphRust, mapping: &HashMap<usize, usize>) -> Self {
+        let num_nodes = mapping.len();
+        let mut rows: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); num_nodes];
+        for ((u, v), weight) in hypergraph.two_section() {
+            let (i, j) = (mapping[&u], mapping[&v]);
+            rows[i].insert(j, weight);
+            rows[j].insert(i, weight);
+        }
+
+        let nnz = rows.iter().map(|row| row.len()).sum();
+        let mut row_ptr = Vec::with_capacity(num_nodes + 1);
+        let mut col_idx = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+        row_ptr.push(0);
+        for row in rows {
+            for (col, value) in row {
+                col_idx.push(col);
+                values.push(value);
+            }
+            row_ptr.push(col_idx.len());
+        }
+
+        CsrMatrix { row_ptr, col_idx, values }
+    }
+
+    /// Number of rows of the matrix.
+    fn nrows(&self) -> usize {
+        self.row_ptr.len() - 1
+    }
+
+    /// Sparse matrix-vector product.
+    fn mul_vec(&self, x: &DVector<f64>) -> DVector<f64> {
+        DVector::from_iterator(
+            self.nrows(),
+            self.row_ptr.windows(2).map(|w| {
+                (w[0]..w[1])
+                    .map(|k| self.values[k] * x[self.col_idx[k]])
+                    .sum::<f64>()
+            }),
+        )
+    }
+}
+
+/// Performs power iteration method to find the dominant eigenvector of a sparse matrix.
 /// 
 /// # Arguments
-/// * `w_matrix` - The square matrix to find the dominant eigenvector for
+/// * `w_matrix` - The square sparse matrix to find the dominant eigenvector for
 /// * `tol` - Tolerance for convergence
 /// * `max_iter` - Maximum number of iterations
 /// 
 /// # Returns
-/// * `Ok(DVector<f64>)` - The dominant eigenvector if convergence is reached
-/// * `Err(String)` - Error message if maximum iterations are reached without convergence
-fn power_iteration(w_matrix: &DMatrix<f64>, tol: f64, max_iter: usize) -> Result<DVector<f64>, String> {
+/// * `(DVector<f64>, ConvergenceInfo)` - The last iterate and its convergence diagnostics
+fn power_iteration(w_matrix: &CsrMatrix, tol: f64, max_iter: usize) -> (DVector<f64>, ConvergenceInfo) {
     let mut x = DVector::from_element(w_matrix.nrows(), 1.0);
     x = x.clone() / x.norm();
     let mut res = f64::INFINITY;
     let mut k = 0;
 
     while res > tol && k < max_iter {
-        let y = w_matrix * &x;
+        let y = w_matrix.mul_vec(&x);
         let y_norm = y.norm();
         res = (&x - &y / y_norm).norm();
         x = y / y_norm;
         k += 1;
     }
 
-    if k >= max_iter {
-        return Err("Metodo di potenza non convergente.".to_string());
-    }
+    let info = ConvergenceInfo {
+        iterations: k,
+        residual: res,
+        converged: res <= tol,
+    };
 
-    Ok(x)
+    (x, info)
 }
 
 /// Calculates the Clique Eigenvector Centrality (CEC) for nodes in a uniform hypergraph.
 /// 
+/// The centrality is the dominant eigenvector of the two-section adjacency, whose entry for a
+/// pair of nodes is the sum of the weights (multiplicities, if unweighted) of the hyperedges
+/// containing both; it is the matrix returned by `HypergraphRust::adjacency_matrix`. Each pair
+/// is counted once per shared hyperedge, so with unit weights the entry is the number of shared edges.
+/// 
 /// # Arguments
 /// * `hypergraph` - The input hypergraph
 /// * `tol` - Tolerance for convergence
@@ -45,33 +131,120 @@ fn power_iteration(w_matrix: &DMatrix<f64>, tol: f64, max_iter: usize) -> Result
 /// 
 /// # Returns
 /// * `Ok(HashMap<usize, f64>)` - Map of node indices to their centrality values
-/// * `Err(String)` - Error if the hypergraph is not uniform or not connected
+/// * `Err(HypergraphError)` - Error if the hypergraph is not uniform or not connected, or if
+///   maximum iterations are reached without convergence
 pub fn cec_centrality_sequential(
     hypergraph: &HypergraphRust, 
     tol: f64, 
     max_iter: usize
-) -> Result<HashMap<usize, f64>, String> {
+) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let (cec, info) = cec_centrality_with_info(hypergraph, tol, max_iter)?;
+    if !info.converged {
+        return Err(HypergraphError::NotConverged.with_message("Metodo di potenza non convergente."));
+    }
+    Ok(cec)
+}
+
+/// Calculates the Clique Eigenvector Centrality (CEC) for nodes in a uniform hypergraph,
+/// together with the convergence diagnostics of the power iteration.
+/// 
+/// Unlike `cec_centrality_sequential`, reaching `max_iter` is not an error: the last
+/// iterate is returned and `ConvergenceInfo::converged` is `false`.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `Ok((HashMap<usize, f64>, ConvergenceInfo))` - Centrality values and diagnostics
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty, not uniform or not connected
+pub fn cec_centrality_with_info(
+    hypergraph: &HypergraphRust, 
+    tol: f64, 
+    max_iter: usize
+) -> Result<(HashMap<usize, f64>, ConvergenceInfo), HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
     if !hypergraph.is_uniform() {
-        return Err("L'ipergrafo non è uniforme.".to_string());
+        return Err(HypergraphError::NotUniform.with_message("L'ipergrafo non è uniforme."));
     }
     if !hypergraph.is_connected_rust() {
-        return Err("L'ipergrafo non è connesso.".to_string());
+        return Err(HypergraphError::NotConnected.with_message("L'ipergrafo non è connesso."));
     }
 
     let num_nodes = hypergraph.num_nodes();
-    let mut w_matrix = DMatrix::from_element(num_nodes, num_nodes, 0.0);
-    
-    for edge in hypergraph.get_edges(false, None, None, false)? {
-        for i in 0..edge.len() {
-            for j in (i + 1)..edge.len() {
-                w_matrix[(edge[i], edge[j])] += 1.0;
-                w_matrix[(edge[j], edge[i])] += 1.0;
-            }
-        }
+    let encoder = hypergraph.get_mapping()?;
+    let w_matrix = CsrMatrix::two_section(hypergraph, &encoder.get_mapping());
+
+    let (dominant_eig, info) = power_iteration(&w_matrix, tol, max_iter);
+    let cec = (0..num_nodes)
+        .filter_map(|idx| encoder.inverse_transform(idx).map(|node| (node, dominant_eig[idx])))
+        .collect();
+    Ok((cec, info))
+}
+
+/// Calculates the Clique Eigenvector Centrality (CEC) for nodes in a possibly non-uniform hypergraph.
+/// 
+/// Only the clique reduction is used: the hypergraph is projected onto its weighted
+/// two-section, where every pair of nodes sharing a hyperedge is linked with a weight equal
+/// to the sum of the weights of the hyperedges containing both, and the dominant eigenvector
+/// of that adjacency is returned. Edge orders play no further role.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - Map of node indices to their centrality values
+/// * `Err(HypergraphError)` - Error if the hypergraph is not connected, or if maximum iterations
+///   are reached without convergence
+pub fn cec_centrality_general(
+    hypergraph: &HypergraphRust,
+    tol: f64,
+    max_iter: usize
+) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let (cec, info) = cec_centrality_general_with_info(hypergraph, tol, max_iter)?;
+    if !info.converged {
+        return Err(HypergraphError::NotConverged);
+    }
+    Ok(cec)
+}
+
+/// Calculates the clique-reduction CEC of `cec_centrality_general`, together with the
+/// convergence diagnostics of the power iteration.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `Ok((HashMap<usize, f64>, ConvergenceInfo))` - Centrality values and diagnostics
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty or not connected
+pub fn cec_centrality_general_with_info(
+    hypergraph: &HypergraphRust,
+    tol: f64,
+    max_iter: usize
+) -> Result<(HashMap<usize, f64>, ConvergenceInfo), HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
     }
+    if !hypergraph.is_connected_rust() {
+        return Err(HypergraphError::NotConnected);
+    }
+
+    let num_nodes = hypergraph.num_nodes();
+    let encoder = hypergraph.get_mapping()?;
+    let w_matrix = CsrMatrix::two_section(hypergraph, &encoder.get_mapping());
 
-    let dominant_eig = power_iteration(&w_matrix, tol, max_iter)?;
-    Ok((0..num_nodes).map(|node| (node, dominant_eig[node])).collect())
+    let (dominant_eig, info) = power_iteration(&w_matrix, tol, max_iter);
+    let cec = (0..num_nodes)
+        .filter_map(|idx| encoder.inverse_transform(idx).map(|node| (node, dominant_eig[idx])))
+        .collect();
+    Ok((cec, info))
 }
 
 /// Calculates the Z-eigenvector Centrality (ZEC) for nodes in a uniform hypergraph.
@@ -86,25 +259,49 @@ pub fn cec_centrality_sequential(
 /// 
 /// # Returns
 /// * `Ok(BTreeMap<usize, f64>)` - Ordered map of node indices to their centrality values
-/// * `Err(String)` - Error if the hypergraph is not uniform or not connected
+/// * `Err(HypergraphError)` - Error if the hypergraph is not uniform or not connected
 pub fn zec_centrality_rust(
     hypergraph: &HypergraphRust,
     max_iter: usize,
     tol: f64,
-) -> Result<BTreeMap<usize, f64>, String> {
-    
+) -> Result<BTreeMap<usize, f64>, HypergraphError> {
+    zec_centrality_with_info(hypergraph, max_iter, tol).map(|(zec, _)| zec)
+}
+
+/// Calculates the Z-eigenvector Centrality (ZEC) together with the convergence diagnostics
+/// of the iteration.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `max_iter` - Maximum number of iterations
+/// * `tol` - Tolerance for convergence
+/// 
+/// # Returns
+/// * `Ok((BTreeMap<usize, f64>, ConvergenceInfo))` - Centrality values and diagnostics
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty, not uniform or not connected
+pub fn zec_centrality_with_info(
+    hypergraph: &HypergraphRust,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(BTreeMap<usize, f64>, ConvergenceInfo), HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
     if !hypergraph.is_uniform() {
-        return Err("The hypergraph is not uniform.".to_string());
+        return Err(HypergraphError::NotUniform);
     }
     if !hypergraph.is_connected_rust() {
-        return Err("The hypergraph is not connected.".to_string());
+        return Err(HypergraphError::NotConnected);
     }
 
+    let num_nodes = hypergraph.num_nodes();
+    let encoder = hypergraph.get_mapping()?;
+    let mapping = encoder.get_mapping();
+
     let g = |v: &Vec<f64>, edge: &Vec<usize>| -> f64 {
-        edge.iter().map(|&node| v[node]).product()
+        edge.iter().map(|node| v[mapping[node]]).product()
     };
 
-    let num_nodes = hypergraph.num_nodes();
     let mut rng = rand::thread_rng();
     let mut x: Vec<f64> = (0..num_nodes).map(|_| rng.gen()).collect();
 
@@ -113,12 +310,19 @@ pub fn zec_centrality_rust(
         *xi /= norm;
     }
 
+    let mut info = ConvergenceInfo {
+        iterations: 0,
+        residual: f64::INFINITY,
+        converged: false,
+    };
+
     for _ in 0..max_iter {
+        info.iterations += 1;
         let mut new_x = vec![0.0; num_nodes];
-        for edge in hypergraph.get_edges(false, None, None, false).map_err(|e| e.to_string())? {
+        for edge in hypergraph.get_edges(false, None, None, false)? {
             let edge_value = g(&x, edge);
             for node in edge.iter() {
-                new_x[*node] += edge_value;
+                new_x[mapping[node]] += edge_value;
             }
         }
 
@@ -133,18 +337,22 @@ pub fn zec_centrality_rust(
             .map(|(xi, new_xi)| (xi - new_xi).abs())
             .sum::<f64>();
 
+        info.residual = diff;
         if diff <= tol {
+            info.converged = true;
             break;
         }
         x = new_x;
     }
 
     let mut zec = BTreeMap::new();
-    for (node, &value) in x.iter().enumerate() {
-        zec.insert(node, value);
+    for (idx, &value) in x.iter().enumerate() {
+        if let Some(node) = encoder.inverse_transform(idx) {
+            zec.insert(node, value);
+        }
     }
 
-    Ok(zec)
+    Ok((zec, info))
 }
 
 /// Calculates the H-eigenvector Centrality (HEC) for nodes in a uniform hypergraph.
@@ -160,22 +368,56 @@ pub fn zec_centrality_rust(
 /// # Returns
 /// * `Ok(BTreeMap<usize, f64>)` - Ordered map of node indices to their centrality values,
 ///                                sorted in descending order by centrality
-/// * `Err(String)` - Error if the hypergraph is not uniform, not connected, or if
+/// * `Err(HypergraphError)` - Error if the hypergraph is not uniform, not connected, or if
 ///                   maximum iterations are reached without convergence
 pub fn hec_centrality_rust(
     hypergraph: &HypergraphRust,
     tol: f64,
     max_iter: usize
-) -> Result<BTreeMap<usize, f64>, String> {
+) -> Result<BTreeMap<usize, f64>, HypergraphError> {
+    let (hec, info) = hec_centrality_with_info(hypergraph, tol, max_iter)?;
+    if !info.converged {
+        return Err(HypergraphError::NotConverged.with_message("Maximum iterations reached without convergence"));
+    }
+    Ok(hec)
+}
+
+/// Calculates the H-eigenvector Centrality (HEC) together with the convergence diagnostics
+/// of the iteration.
+/// 
+/// Unlike `hec_centrality_rust`, reaching `max_iter` is not an error: the last iterate is
+/// returned and `ConvergenceInfo::converged` is `false`.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// 
+/// # Returns
+/// * `Ok((BTreeMap<usize, f64>, ConvergenceInfo))` - Centrality values and diagnostics
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty, not uniform or not connected
+pub fn hec_centrality_with_info(
+    hypergraph: &HypergraphRust,
+    tol: f64,
+    max_iter: usize
+) -> Result<(BTreeMap<usize, f64>, ConvergenceInfo), HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
     if !hypergraph.is_uniform() {
-        return Err("The hypergraph is not uniform.".to_string());
+        return Err(HypergraphError::NotUniform);
     }
     if !hypergraph.is_connected_rust() {
-        return Err("The hypergraph is not connected.".to_string());
+        return Err(HypergraphError::NotConnected);
     }
 
     let num_nodes = hypergraph.num_nodes();
-    let edges = hypergraph.get_edges(false, None, None, false)?;
+    let encoder = hypergraph.get_mapping()?;
+    let mapping = encoder.get_mapping();
+    let edges: Vec<Vec<usize>> = hypergraph.get_edges(false, None, None, false)?
+        .into_iter()
+        .map(|edge| edge.iter().map(|node| mapping[node]).collect())
+        .collect();
     let m = edges[0].len(); // m is the size of each edge (uniformity)
 
     let mut x = {
@@ -190,8 +432,14 @@ pub fn hec_centrality_rust(
     };
 
     let mut new_x = DVector::zeros(num_nodes);
+    let mut info = ConvergenceInfo {
+        iterations: 0,
+        residual: f64::INFINITY,
+        converged: false,
+    };
 
     for _ in 0..max_iter {
+        info.iterations += 1;
         new_x.fill(0.0);
         for edge in edges.iter() {
             for &i in edge.iter() {
@@ -211,20 +459,50 @@ pub fn hec_centrality_rust(
         let norm = new_x.norm();
         new_x.scale_mut(1.0 / norm);
 
-        let diff = (&x - &new_x).norm();
-        if diff <= tol {
-            let mut centrality_vec: Vec<(usize, f64)> = new_x.iter()
-                .enumerate()
-                .map(|(i, &v)| (i, v))
-                .collect();
-            
-            centrality_vec.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-            
-            return Ok(centrality_vec.into_iter().collect());
+        info.residual = (&x - &new_x).norm();
+        std::mem::swap(&mut x, &mut new_x);
+        if info.residual <= tol {
+            info.converged = true;
+            break;
         }
+    }
 
-        std::mem::swap(&mut x, &mut new_x);
+    let mut centrality_vec: Vec<(usize, f64)> = x.iter()
+        .enumerate()
+        .filter_map(|(i, &v)| encoder.inverse_transform(i).map(|node| (node, v)))
+        .collect();
+    
+    centrality_vec.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    
+    Ok((centrality_vec.into_iter().collect(), info))
+}
+
+/// Calculates the subgraph communicability of every node in a hypergraph.
+/// 
+/// The self-communicability of a node is the corresponding diagonal entry of `exp(A)`, where `A`
+/// is the binary two-section adjacency: it counts the closed walks starting and ending at the node,
+/// each walk of length `k` discounted by `1 / k!`.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// 
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - Map of node indices to their self-communicability
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn communicability_rust(hypergraph: &HypergraphRust) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let num_nodes = hypergraph.num_nodes();
+    let encoder = hypergraph.get_mapping()?;
+    let mapping = encoder.get_mapping();
+
+    let mut adjacency = DMatrix::<f64>::zeros(num_nodes, num_nodes);
+    for (u, v) in hypergraph.two_section().into_keys() {
+        let (i, j) = (mapping[&u], mapping[&v]);
+        adjacency[(i, j)] = 1.0;
+        adjacency[(j, i)] = 1.0;
     }
 
-    Err("Maximum iterations reached without convergence".to_string())
+    let exponential = adjacency.exp();
+    Ok((0..num_nodes)
+        .filter_map(|idx| encoder.inverse_transform(idx).map(|node| (node, exponential[(idx, idx)])))
+        .collect())
 }
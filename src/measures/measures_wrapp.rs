@@ -1,10 +1,16 @@
-use std::collections::{HashMap, HashSet, BTreeMap};
+use std::collections::{HashMap, HashSet};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rustworkx_core::petgraph::visit::EdgeRef;
+use crate::core::error::HypergraphError;
 use crate::core::hypergraph_wrapp::Hypergraph;
+use super::centrality_utils_rust::*;
 use super::degree_rust::*;
 use super::edge_similarity_rust::*;
 use super::eigen_centralities_rust::*;
+use super::path_centralities_rust::*;
 use super::s_centralities_rust::*;
+use super::spectral_rust::*;
 
 /// Python wrapper for computing the degree of a node in a hypergraph.
 /// 
@@ -54,6 +60,27 @@ pub fn degree_sequence(
     })
 }
 
+/// Converts a dense matrix into the Python object returned to the caller.
+/// 
+/// With the `numpy` feature enabled the matrix is copied straight into a 2-D `numpy.ndarray`
+/// of `float64`, without building the nested lists; otherwise it is returned as nested lists.
+/// 
+/// # Arguments
+/// * `py` - Python GIL token
+/// * `matrix` - Row-major dense matrix
+/// 
+/// # Returns
+/// * `PyResult<PyObject>` - The converted matrix
+#[cfg(feature = "numpy")]
+fn matrix_to_py(py: Python<'_>, matrix: Vec<Vec<f64>>) -> PyResult<PyObject> {
+    Ok(numpy::PyArray2::from_vec2_bound(py, &matrix)?.into_any().unbind())
+}
+
+#[cfg(not(feature = "numpy"))]
+fn matrix_to_py(py: Python<'_>, matrix: Vec<Vec<f64>>) -> PyResult<PyObject> {
+    Ok(matrix.into_py(py))
+}
+
 /// Python wrapper for computing the degree correlation matrix of a hypergraph.
 /// 
 /// Computes correlations between degree sequences for different edge sizes.
@@ -62,15 +89,17 @@ pub fn degree_sequence(
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// 
 /// # Returns
-/// * `PyResult<Option<Vec<Vec<f64>>>>` - Matrix of correlation coefficients
+/// * `PyResult<PyObject>` - Matrix of correlation coefficients, as a `numpy.ndarray` when
+///   built with the `numpy` feature and as nested lists otherwise
 /// * Raises `PyValueError` if computation fails
 #[pyfunction]
 #[pyo3(signature = (hypergraph), name = "degree_correlation")]
-pub fn degree_correlation(hypergraph: &Hypergraph) -> PyResult<Option<Vec<Vec<f64>>>> {
+pub fn degree_correlation(py: Python<'_>, hypergraph: &Hypergraph) -> PyResult<PyObject> {
     let hypergraph_rust = &hypergraph.inner;
-    Ok(Some(degree_correlation_rust(hypergraph_rust).map_err(|e| {
+    let matrix = degree_correlation_rust(hypergraph_rust).map_err(|e| {
         PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing degree correlation: {}", e))
-    })?))
+    })?;
+    matrix_to_py(py, matrix)
 }
 
 /// Python wrapper for computing the degree distribution of a hypergraph.
@@ -92,6 +121,151 @@ pub fn degree_distribution(hypergraph: &Hypergraph, order: Option<usize>, size:
     })
 }
 
+/// Python wrapper for computing the degree distribution of a hypergraph as probabilities.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `PyResult<HashMap<u64, f64>>` - Map of degrees to the fraction of nodes with that degree
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, order=None, size=None), name = "degree_distribution_normalized")]
+pub fn degree_distribution_normalized(hypergraph: &Hypergraph, order: Option<usize>, size: Option<usize>) -> PyResult<HashMap<u64, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    degree_distribution_normalized_rust(hypergraph_rust, order, size).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing degree distribution: {}", e))
+    })
+}
+
+/// Python wrapper for computing the complementary cumulative degree distribution of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `PyResult<Vec<(u64, f64)>>` - Pairs `(k, P(degree >= k))` sorted by degree
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, order=None, size=None), name = "degree_distribution_cdf")]
+pub fn degree_distribution_cdf(hypergraph: &Hypergraph, order: Option<usize>, size: Option<usize>) -> PyResult<Vec<(u64, f64)>> {
+    let hypergraph_rust = &hypergraph.inner;
+    degree_distribution_cdf_rust(hypergraph_rust, order, size).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing degree distribution: {}", e))
+    })
+}
+
+/// Python wrapper for computing the entropy of the degree distribution of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `order` - Optional order constraint for incident edges
+/// * `size` - Optional size constraint for incident edges
+/// 
+/// # Returns
+/// * `PyResult<f64>` - Shannon entropy of the degree distribution
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, order=None, size=None), name = "degree_entropy")]
+pub fn degree_entropy(hypergraph: &Hypergraph, order: Option<usize>, size: Option<usize>) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    degree_entropy_rust(hypergraph_rust, order, size).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing degree entropy: {}", e))
+    })
+}
+
+/// Python wrapper for computing the degree mixing matrix of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<(Vec<u64>, Vec<Vec<f64>>)>` - Sorted degree values and the normalized joint distribution
+///   of the degrees of co-occurring node pairs
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "degree_mixing_matrix")]
+pub fn degree_mixing_matrix(hypergraph: &Hypergraph) -> PyResult<(Vec<u64>, Vec<Vec<f64>>)> {
+    let hypergraph_rust = &hypergraph.inner;
+    degree_mixing_matrix_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing degree mixing matrix: {}", e))
+    })
+}
+
+/// Python wrapper for computing the average neighbor degree of every node.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - Map of node indices to the mean degree of their neighbors
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "average_neighbor_degree")]
+pub fn average_neighbor_degree(hypergraph: &Hypergraph) -> PyResult<HashMap<usize, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    average_neighbor_degree_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing average neighbor degree: {}", e))
+    })
+}
+
+/// Python wrapper for computing the degree of a node in the weighted two-section of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `node` - Node to compute the projected degree for
+/// 
+/// # Returns
+/// * `PyResult<f64>` - The sum of the two-section weights incident to the node
+/// * Raises `PyValueError` if the node is not in the hypergraph
+#[pyfunction]
+#[pyo3(signature = (hypergraph, node), name = "weighted_projection_degree")]
+pub fn weighted_projection_degree(hypergraph: &Hypergraph, node: usize) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    weighted_projection_degree_rust(hypergraph_rust, node).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing weighted projection degree: {}", e))
+    })
+}
+
+/// Python wrapper for computing Burt's effective size of a node.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `node` - Node to compute the effective size for
+/// 
+/// # Returns
+/// * `PyResult<f64>` - The effective size of the node on the two-section
+/// * Raises `PyValueError` if the node is not in the hypergraph
+#[pyfunction]
+#[pyo3(signature = (hypergraph, node), name = "effective_size")]
+pub fn effective_size(hypergraph: &Hypergraph, node: usize) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    effective_size_rust(hypergraph_rust, node).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing effective size: {}", e))
+    })
+}
+
+/// Python wrapper for computing the k-nn function of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<HashMap<u64, f64>>` - Map from each degree `k` to the average neighbor degree of the nodes of degree `k`
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "knn")]
+pub fn knn(hypergraph: &Hypergraph) -> PyResult<HashMap<u64, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    knn_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing knn: {}", e))
+    })
+}
+
 /// Python wrapper for computing the intersection size between two hyperedges.
 /// 
 /// # Arguments
@@ -139,23 +313,159 @@ pub fn jaccard_distance(hyperedge_a: HashSet<usize>, hyperedge_b: HashSet<usize>
     jaccard_distance_rust(&hyperedge_a, &hyperedge_b)
 }
 
+/// Python wrapper for computing the overlap coefficient between two hyperedges.
+/// 
+/// The overlap (Szymkiewicz-Simpson) coefficient is defined as the size of the intersection
+/// divided by the size of the smaller set.
+/// 
+/// # Arguments
+/// * `hyperedge_a` - First hyperedge as a set of node indices
+/// * `hyperedge_b` - Second hyperedge as a set of node indices
+/// 
+/// # Returns
+/// * `f64` - A value between 0 (disjoint, or an empty hyperedge) and 1 (one contains the other)
+#[pyfunction]
+#[pyo3(name = "overlap_coefficient")]
+pub fn overlap_coefficient(hyperedge_a: HashSet<usize>, hyperedge_b: HashSet<usize>) -> f64 {
+    overlap_coefficient_rust(&hyperedge_a, &hyperedge_b)
+}
+
+/// Python wrapper for computing the Sorensen-Dice coefficient between two hyperedges.
+/// 
+/// The Dice coefficient is defined as twice the size of the intersection divided by
+/// the sum of the sizes of the two sets.
+/// 
+/// # Arguments
+/// * `hyperedge_a` - First hyperedge as a set of node indices
+/// * `hyperedge_b` - Second hyperedge as a set of node indices
+/// 
+/// # Returns
+/// * `f64` - A value between 0 (completely different) and 1 (identical)
+#[pyfunction]
+#[pyo3(name = "dice_coefficient")]
+pub fn dice_coefficient(hyperedge_a: HashSet<usize>, hyperedge_b: HashSet<usize>) -> f64 {
+    dice_coefficient_rust(&hyperedge_a, &hyperedge_b)
+}
+
+/// Python wrapper for computing the pairwise similarity matrix of the edges of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `metric` - Similarity to use, one of "jaccard", "overlap" or "dice"
+/// 
+/// # Returns
+/// * `PyResult<SimilarityMatrix>` - The sorted edges and the E×E similarity matrix
+/// * Raises `PyValueError` if `metric` is unknown
+#[pyfunction]
+#[pyo3(signature = (hypergraph, metric="jaccard"), name = "edge_similarity_matrix")]
+pub fn edge_similarity_matrix(hypergraph: &Hypergraph, metric: &str) -> PyResult<SimilarityMatrix> {
+    let hypergraph_rust = &hypergraph.inner;
+    edge_similarity_matrix_rust(hypergraph_rust, metric).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing edge similarity matrix: {}", e))
+    })
+}
+
+/// Python wrapper for computing the similarity between two nodes from the edges they belong to.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `u` - First node
+/// * `v` - Second node
+/// * `metric` - Similarity to use, either "cosine" or "jaccard"
+/// 
+/// # Returns
+/// * `PyResult<f64>` - The similarity of the two incidence vectors, 0 if either node is isolated
+/// * Raises `PyValueError` if a node is missing or `metric` is unknown
+#[pyfunction]
+#[pyo3(signature = (hypergraph, u, v, metric="cosine"), name = "node_similarity")]
+pub fn node_similarity(hypergraph: &Hypergraph, u: usize, v: usize, metric: &str) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    node_similarity_rust(hypergraph_rust, u, v, metric).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing node similarity: {}", e))
+    })
+}
+
+/// Python wrapper for computing the pairwise similarity matrix of the nodes of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `metric` - Similarity to use, either "cosine" or "jaccard"
+/// 
+/// # Returns
+/// * `PyResult<(Vec<usize>, Vec<Vec<f64>>)>` - The sorted nodes and the N×N similarity matrix
+/// * Raises `PyValueError` if `metric` is unknown
+#[pyfunction]
+#[pyo3(signature = (hypergraph, metric="cosine"), name = "node_similarity_matrix")]
+pub fn node_similarity_matrix(hypergraph: &Hypergraph, metric: &str) -> PyResult<(Vec<usize>, Vec<Vec<f64>>)> {
+    let hypergraph_rust = &hypergraph.inner;
+    node_similarity_matrix_rust(hypergraph_rust, metric).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing node similarity matrix: {}", e))
+    })
+}
+
+/// Converts convergence diagnostics into a Python dict with the keys
+/// `iterations`, `residual` and `converged`.
+fn convergence_dict(py: Python, info: &ConvergenceInfo) -> PyResult<PyObject> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("iterations", info.iterations)?;
+    dict.set_item("residual", info.residual)?;
+    dict.set_item("converged", info.converged)?;
+    Ok(dict.into())
+}
+
 /// Python wrapper for computing the Clique Eigenvector Centrality (CEC) of nodes in a hypergraph.
 /// 
 /// # Arguments
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// * `tol` - Tolerance for convergence
 /// * `max_iter` - Maximum number of iterations
+/// * `diagnostics` - If `true`, also return the convergence diagnostics
 /// 
 /// # Returns
-/// * `PyResult<HashMap<usize, f64>>` - Map of node indices to their centrality values
+/// * `PyResult<PyObject>` - Map of node indices to their centrality values, or a
+///   `(centrality, diagnostics)` tuple when `diagnostics` is `true`
 /// * Raises `PyValueError` if computation fails
 #[pyfunction]
-#[pyo3(name = "cec_centrality")]
-pub fn cec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyResult<HashMap<usize, f64>> {
+#[pyo3(signature = (hypergraph, tol, max_iter, diagnostics=false), name = "cec_centrality")]
+pub fn cec_centrality(py: Python, hypergraph: &Hypergraph, tol: f64, max_iter: usize, diagnostics: bool) -> PyResult<PyObject> {
     let hypergraph_rust = &hypergraph.inner;
-    cec_centrality_sequential(hypergraph_rust, tol, max_iter).map_err(|e| {
+    let to_py_err = |e: HypergraphError| {
         PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing CEC: {}", e))
-    })
+    };
+    if diagnostics {
+        let (cec, info) = cec_centrality_with_info(hypergraph_rust, tol, max_iter).map_err(to_py_err)?;
+        Ok((cec, convergence_dict(py, &info)?).into_py(py))
+    } else {
+        Ok(cec_centrality_sequential(hypergraph_rust, tol, max_iter).map_err(to_py_err)?.into_py(py))
+    }
+}
+
+/// Python wrapper for computing the Clique Eigenvector Centrality (CEC) of nodes in a
+/// non-uniform hypergraph, using only its clique reduction.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `tol` - Tolerance for convergence
+/// * `max_iter` - Maximum number of iterations
+/// * `diagnostics` - If `true`, also return the convergence diagnostics
+/// 
+/// # Returns
+/// * `PyResult<PyObject>` - Map of node indices to their centrality values, or a
+///   `(centrality, diagnostics)` tuple when `diagnostics` is `true`
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, tol, max_iter, diagnostics=false), name = "cec_centrality_general")]
+pub fn cec_centrality_general_py(py: Python, hypergraph: &Hypergraph, tol: f64, max_iter: usize, diagnostics: bool) -> PyResult<PyObject> {
+    let hypergraph_rust = &hypergraph.inner;
+    let to_py_err = |e: HypergraphError| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing CEC: {}", e))
+    };
+    if diagnostics {
+        let (cec, info) = cec_centrality_general_with_info(hypergraph_rust, tol, max_iter).map_err(to_py_err)?;
+        Ok((cec, convergence_dict(py, &info)?).into_py(py))
+    } else {
+        Ok(cec_centrality_general(hypergraph_rust, tol, max_iter).map_err(to_py_err)?.into_py(py))
+    }
 }
 
 /// Python wrapper for computing the Z-Eigenvector Centrality (ZEC) of nodes in a hypergraph.
@@ -164,17 +474,29 @@ pub fn cec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyR
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// * `tol` - Tolerance for convergence
 /// * `max_iter` - Maximum number of iterations
+/// * `diagnostics` - If `true`, also return the convergence diagnostics
 /// 
 /// # Returns
-/// * `PyResult<HashMap<usize, f64>>` - Map of node indices to their centrality values
+/// * `PyResult<PyObject>` - Map of node indices to their centrality values, or a
+///   `(centrality, diagnostics)` tuple when `diagnostics` is `true`
 /// * Raises `PyValueError` if computation fails
 #[pyfunction]
-#[pyo3(name = "zec_centrality")]
-pub fn zec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyResult<HashMap<usize, f64>> {
+#[pyo3(signature = (hypergraph, tol, max_iter, diagnostics=false), name = "zec_centrality")]
+pub fn zec_centrality(py: Python, hypergraph: &Hypergraph, tol: f64, max_iter: usize, diagnostics: bool) -> PyResult<PyObject> {
     let hypergraph_rust = &hypergraph.inner;
-    match zec_centrality_rust(hypergraph_rust, max_iter, tol) {
-        Ok(result) => Ok(result.into_iter().collect()),
-        Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+    if diagnostics {
+        match zec_centrality_with_info(hypergraph_rust, max_iter, tol) {
+            Ok((result, info)) => {
+                let zec: HashMap<usize, f64> = result.into_iter().collect();
+                Ok((zec, convergence_dict(py, &info)?).into_py(py))
+            }
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+        }
+    } else {
+        match zec_centrality_rust(hypergraph_rust, max_iter, tol) {
+            Ok(result) => Ok(result.into_iter().collect::<HashMap<usize, f64>>().into_py(py)),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+        }
     }
 }
 
@@ -184,16 +506,130 @@ pub fn zec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyR
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// * `tol` - Tolerance for convergence
 /// * `max_iter` - Maximum number of iterations
+/// * `diagnostics` - If `true`, also return the convergence diagnostics
 /// 
 /// # Returns
-/// * `PyResult<BTreeMap<usize, f64>>` - Ordered map of node indices to their centrality values
+/// * `PyResult<PyObject>` - Ordered map of node indices to their centrality values, or a
+///   `(centrality, diagnostics)` tuple when `diagnostics` is `true`
 /// * Raises `PyValueError` if computation fails
 #[pyfunction]
-#[pyo3(name = "hec_centrality")]
-pub fn hec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyResult<BTreeMap<usize, f64>> {
+#[pyo3(signature = (hypergraph, tol, max_iter, diagnostics=false), name = "hec_centrality")]
+pub fn hec_centrality(py: Python, hypergraph: &Hypergraph, tol: f64, max_iter: usize, diagnostics: bool) -> PyResult<PyObject> {
     let hypergraph_rust = &hypergraph.inner;
-    hec_centrality_rust(hypergraph_rust, tol, max_iter).map_err(|e| {
+    let to_py_err = |e: HypergraphError| {
         PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing HEC: {}", e))
+    };
+    if diagnostics {
+        let (hec, info) = hec_centrality_with_info(hypergraph_rust, tol, max_iter).map_err(to_py_err)?;
+        Ok((hec, convergence_dict(py, &info)?).into_py(py))
+    } else {
+        Ok(hec_centrality_rust(hypergraph_rust, tol, max_iter).map_err(to_py_err)?.into_py(py))
+    }
+}
+
+/// Python wrapper for computing the subgraph communicability of nodes in a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - Map of node indices to the diagonal entries of `exp(A)`
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "communicability")]
+pub fn communicability(hypergraph: &Hypergraph) -> PyResult<HashMap<usize, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    communicability_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing communicability: {}", e))
+    })
+}
+
+/// Python wrapper for computing the harmonic centrality of nodes in a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - Map of node indices to the sum of their reciprocal distances
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "harmonic_centrality")]
+pub fn harmonic_centrality(hypergraph: &Hypergraph) -> PyResult<HashMap<usize, f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    harmonic_centrality_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing harmonic centrality: {}", e))
+    })
+}
+
+/// Python wrapper for computing the spectral embedding of the nodes of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `dims` - Number of coordinates of the embedding
+/// * `normalized` - Whether to use the normalized Laplacian of the two-section
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, Vec<f64>>>` - Map of node indices to their coordinates
+/// * Raises `PyValueError` if the hypergraph is not connected or `dims` is out of range
+#[pyfunction]
+#[pyo3(signature = (hypergraph, dims=2, normalized=true), name = "spectral_embedding")]
+pub fn spectral_embedding(hypergraph: &Hypergraph, dims: usize, normalized: bool) -> PyResult<HashMap<usize, Vec<f64>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    spectral_embedding_rust(hypergraph_rust, dims, normalized).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing spectral embedding: {}", e))
+    })
+}
+
+/// Python wrapper for computing the spectrum of the two-section adjacency of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// 
+/// # Returns
+/// * `PyResult<Vec<f64>>` - The eigenvalues, in ascending order
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph), name = "adjacency_spectrum")]
+pub fn adjacency_spectrum(hypergraph: &Hypergraph) -> PyResult<Vec<f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    adjacency_spectrum_rust(hypergraph_rust).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing adjacency spectrum: {}", e))
+    })
+}
+
+/// Python wrapper for computing the spectrum of the two-section Laplacian of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `normalized` - Whether to use the normalized Laplacian
+/// 
+/// # Returns
+/// * `PyResult<Vec<f64>>` - The eigenvalues, in ascending order
+/// * Raises `PyValueError` if computation fails
+#[pyfunction]
+#[pyo3(signature = (hypergraph, normalized=false), name = "laplacian_spectrum")]
+pub fn laplacian_spectrum(hypergraph: &Hypergraph, normalized: bool) -> PyResult<Vec<f64>> {
+    let hypergraph_rust = &hypergraph.inner;
+    laplacian_spectrum_rust(hypergraph_rust, normalized).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing Laplacian spectrum: {}", e))
+    })
+}
+
+/// Python wrapper for computing the conductance of a node subset in a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `subset` - Nodes on one side of the partition
+/// 
+/// # Returns
+/// * `PyResult<f64>` - The conductance of the cut on the weighted two-section
+/// * Raises `PyValueError` if a node is missing or either side has zero volume
+#[pyfunction]
+#[pyo3(signature = (hypergraph, subset), name = "conductance")]
+pub fn conductance(hypergraph: &Hypergraph, subset: Vec<usize>) -> PyResult<f64> {
+    let hypergraph_rust = &hypergraph.inner;
+    conductance_rust(hypergraph_rust, &subset).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing conductance: {}", e))
     })
 }
 
@@ -202,14 +638,20 @@ pub fn hec_centrality(hypergraph: &Hypergraph, tol: f64, max_iter: usize) -> PyR
 /// # Arguments
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// * `s` - Threshold value for edge connectivity
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
+/// * `weighted` - Whether to weight the line graph edges by their distance
 /// 
 /// # Returns
 /// * `PyResult<HashMap<String, f64>>` - Map of edge identifiers to their betweenness values
+/// * Raises `PyValueError` if `distance_type` is unknown
 #[pyfunction]
-#[pyo3(name = "s_betweenness")]
-pub fn s_betweenness(hypergraph: &Hypergraph, s: f64) -> PyResult<HashMap<String, f64>> {
+#[pyo3(signature = (hypergraph, s, distance_type="intersection", weighted=false), name = "s_betweenness")]
+pub fn s_betweenness(hypergraph: &Hypergraph, s: f64, distance_type: &str, weighted: bool) -> PyResult<HashMap<String, f64>> {
     let hypergraph_rust = &hypergraph.inner;
-    Ok(s_betweenness_rust(hypergraph_rust, s).into_iter()
+    let betweenness = s_betweenness_rust(hypergraph_rust, s, distance_type, weighted).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing s-betweenness: {}", e))
+    })?;
+    Ok(betweenness.into_iter()
         .map(|(k, v)| (k.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","), v as f64))
         .collect())
 }
@@ -219,14 +661,129 @@ pub fn s_betweenness(hypergraph: &Hypergraph, s: f64) -> PyResult<HashMap<String
 /// # Arguments
 /// * `hypergraph` - Reference to the Python hypergraph object
 /// * `s` - Threshold value for edge connectivity
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
+/// * `weighted` - Whether to weight the line graph edges by their distance
 /// 
 /// # Returns
 /// * `PyResult<HashMap<String, f64>>` - Map of edge identifiers to their closeness values
+/// * Raises `PyValueError` if `distance_type` is unknown
 #[pyfunction]
-#[pyo3(name = "s_closeness")]
-pub fn s_closeness(hypergraph: &Hypergraph, s: f64) -> PyResult<HashMap<String, f64>> {
+#[pyo3(signature = (hypergraph, s, distance_type="intersection", weighted=false), name = "s_closeness")]
+pub fn s_closeness(hypergraph: &Hypergraph, s: f64, distance_type: &str, weighted: bool) -> PyResult<HashMap<String, f64>> {
     let hypergraph_rust = &hypergraph.inner;
-    Ok(s_closeness_rust(hypergraph_rust, s).into_iter()
+    let closeness = s_closeness_rust(hypergraph_rust, s, distance_type, weighted).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing s-closeness: {}", e))
+    })?;
+    Ok(closeness.into_iter()
         .map(|(k, v)| (k.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","), v))
         .collect())
 }
+
+/// Python wrapper for computing the s-connected components of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `s` - Minimum intersection size for two edges to be adjacent
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<Vec<usize>>>>` - Groups of edges, largest component first
+#[pyfunction]
+#[pyo3(signature = (hypergraph, s=1), name = "s_connected_components")]
+pub fn s_connected_components(hypergraph: &Hypergraph, s: usize) -> PyResult<Vec<Vec<Vec<usize>>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    s_connected_components_rust(hypergraph_rust, s).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing s-connected components: {}", e))
+    })
+}
+
+/// Python wrapper for computing the k-clique communities of the s-line graph of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `k` - Size of the cliques, at least 2
+/// * `s` - Minimum intersection size for two edges to be adjacent
+/// 
+/// # Returns
+/// * `PyResult<Vec<Vec<Vec<usize>>>>` - Overlapping groups of edges, largest community first
+/// * Raises `PyValueError` if `k` is smaller than 2
+#[pyfunction]
+#[pyo3(signature = (hypergraph, k, s=1), name = "clique_percolation")]
+pub fn clique_percolation(hypergraph: &Hypergraph, k: usize, s: usize) -> PyResult<Vec<Vec<Vec<usize>>>> {
+    let hypergraph_rust = &hypergraph.inner;
+    clique_percolation_rust(hypergraph_rust, k, s).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error computing clique percolation: {}", e))
+    })
+}
+
+/// Line graph links as `(edge, edge, weight)` triples, with the hyperedge of each line graph node.
+type LineGraphLinks = (Vec<(Vec<usize>, Vec<usize>, f64)>, HashMap<usize, Vec<usize>>);
+
+/// Python wrapper for building the line graph of a hypergraph.
+/// 
+/// # Arguments
+/// * `hypergraph` - Reference to the Python hypergraph object
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
+/// * `s` - Threshold value for edge connectivity
+/// * `weighted` - Whether to use the distance as the weight of the line graph edges
+/// 
+/// # Returns
+/// * `PyResult<(Vec<(Vec<usize>, Vec<usize>, f64)>, HashMap<usize, Vec<usize>>)>` - The line graph links as
+///   pairs of hyperedges with their weight, and the mapping from line graph node ids to hyperedges
+/// * Raises `PyValueError` if `distance_type` is unknown
+#[pyfunction]
+#[pyo3(signature = (hypergraph, distance_type="intersection", s=1.0, weighted=false), name = "line_graph")]
+pub fn line_graph_py(
+    hypergraph: &Hypergraph,
+    distance_type: &str,
+    s: f64,
+    weighted: bool,
+) -> PyResult<LineGraphLinks> {
+    let hypergraph_rust = &hypergraph.inner;
+    let (graph, id_to_edge) = line_graph(hypergraph_rust, distance_type, s, weighted).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error building line graph: {}", e))
+    })?;
+
+    let links = graph
+        .edge_references()
+        .map(|link| {
+            (
+                id_to_edge[&link.source().index()].clone(),
+                id_to_edge[&link.target().index()].clone(),
+                *link.weight(),
+            )
+        })
+        .collect();
+
+    Ok((links, id_to_edge))
+}
+
+/// Python wrapper for selecting the highest-scoring nodes of a centrality map.
+/// 
+/// # Arguments
+/// * `centrality` - Map of node indices to their centrality values, e.g. the output of `cec_centrality`
+/// * `k` - Number of nodes to return
+/// 
+/// # Returns
+/// * `PyResult<Vec<(usize, f64)>>` - `(node, score)` pairs sorted by decreasing score, ties broken by smallest node
+#[pyfunction]
+#[pyo3(name = "top_k")]
+pub fn top_k(centrality: HashMap<usize, f64>, k: usize) -> PyResult<Vec<(usize, f64)>> {
+    Ok(top_k_rust(&centrality, k))
+}
+
+/// Python wrapper for rescaling a centrality map.
+/// 
+/// # Arguments
+/// * `centrality` - Map of node indices to their centrality values
+/// * `mode` - "max" to make the largest score 1, "l1" or "l2" for a unit-norm vector
+/// 
+/// # Returns
+/// * `PyResult<HashMap<usize, f64>>` - The rescaled centrality map
+/// * Raises `PyValueError` if `mode` is unknown or every score is zero
+#[pyfunction]
+#[pyo3(signature = (centrality, mode="max"), name = "normalize_centrality")]
+pub fn normalize_centrality(centrality: HashMap<usize, f64>, mode: &str) -> PyResult<HashMap<usize, f64>> {
+    normalize_centrality_rust(&centrality, mode).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Error normalizing centrality: {}", e))
+    })
+}
@@ -1,5 +1,8 @@
+pub mod centrality_utils_rust;
 pub mod degree_rust;
 pub mod edge_similarity_rust;
 pub mod measures_wrapp;
 pub mod eigen_centralities_rust;
-pub mod s_centralities_rust;
\ No newline at end of file
+pub mod path_centralities_rust;
+pub mod s_centralities_rust;
+pub mod spectral_rust;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+
+/// Calculates the harmonic centrality of every node in a hypergraph.
+/// 
+/// The harmonic centrality of a node is the sum of the reciprocals of its shortest-hop distances to
+/// all the other nodes. Unreachable nodes contribute 0, so unlike closeness it is well defined on
+/// disconnected hypergraphs.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// 
+/// # Returns
+/// * `Ok(HashMap<usize, f64>)` - Map of node indices to their harmonic centrality
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn harmonic_centrality_rust(hypergraph: &HypergraphRust) -> Result<HashMap<usize, f64>, HypergraphError> {
+    let nodes = hypergraph.get_nodes_without_metadata();
+    if nodes.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
+
+    nodes
+        .into_iter()
+        .map(|node| {
+            let distances = hypergraph.shortest_path_lengths(node)?;
+            let centrality = distances
+                .values()
+                .filter(|&&distance| distance > 0)
+                .map(|&distance| 1.0 / distance as f64)
+                .sum();
+            Ok((node, centrality))
+        })
+        .collect()
+}
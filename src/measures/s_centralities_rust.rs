@@ -1,26 +1,48 @@
-use std::collections::HashMap;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
 use rustworkx_core::centrality::{betweenness_centrality, closeness_centrality};
-use rustworkx_core::petgraph::graph::Graph;
+use rustworkx_core::connectivity::connected_components;
+use rustworkx_core::petgraph::algo::dijkstra;
+use rustworkx_core::petgraph::graph::{Graph, NodeIndex};
+use rustworkx_core::petgraph::visit::EdgeRef;
 use rustworkx_core::petgraph::Undirected;
+use crate::core::error::HypergraphError;
 use crate::core::hypergraph_rust::HypergraphRust;
+use super::edge_similarity_rust::{dice_coefficient_rust, intersection_rust, jaccard_similarity_rust};
 use std::collections::HashSet;
 
+/// A line graph together with the hyperedge represented by each of its nodes.
+pub type LineGraph = (Graph<(), f64, Undirected>, HashMap<usize, Vec<usize>>);
+
 /// Calculates the S-Betweenness centrality for edges in a hypergraph.
 /// 
 /// # Arguments
 /// * `hypergraph` - The input hypergraph
 /// * `s` - Threshold value for edge connectivity
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
+/// * `weighted` - Whether to weight the line graph edges. The weights are similarities, so shortest
+///   paths are computed using `1 / weight` as the length of each link
 /// 
 /// # Returns
-/// A HashMap mapping edge indices to their betweenness centrality values
-pub fn s_betweenness_rust(hypergraph: &HypergraphRust, s: f64) -> HashMap<Vec<usize>, f64> {
-    let (graph, id_to_edge) = line_graph(hypergraph, "intersection", s, false);
-    let betweenness = betweenness_centrality(&graph, false, true, 50);
+/// A HashMap mapping edge indices to their betweenness centrality values, or an error message if
+/// `distance_type` is unknown
+pub fn s_betweenness_rust(
+    hypergraph: &HypergraphRust,
+    s: f64,
+    distance_type: &str,
+    weighted: bool,
+) -> Result<HashMap<Vec<usize>, f64>, HypergraphError> {
+    let (graph, id_to_edge) = line_graph(hypergraph, distance_type, s, weighted)?;
+    let betweenness = if weighted {
+        weighted_betweenness_centrality(&graph)
+    } else {
+        betweenness_centrality(&graph, false, true, 50)
+    };
     
-    betweenness.into_iter()
+    Ok(betweenness.into_iter()
         .enumerate()
         .filter_map(|(k, v)| v.map(|value| (id_to_edge[&k].clone(), value)))
-        .collect()
+        .collect())
 }
 
 /// Calculates the S-Closeness centrality for edges in a hypergraph.
@@ -28,19 +50,290 @@ pub fn s_betweenness_rust(hypergraph: &HypergraphRust, s: f64) -> HashMap<Vec<us
 /// # Arguments
 /// * `hypergraph` - The input hypergraph
 /// * `s` - Threshold value for edge connectivity
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
+/// * `weighted` - Whether to weight the line graph edges. The weights are similarities, so shortest
+///   paths are computed using `1 / weight` as the length of each link
 /// 
 /// # Returns
-/// A HashMap mapping edge indices to their closeness centrality values
-pub fn s_closeness_rust(hypergraph: &HypergraphRust, s: f64) -> HashMap<Vec<usize>, f64> {
-    let (graph, id_to_edge) = line_graph(hypergraph, "intersection", s, false);
-    let closeness = closeness_centrality(&graph, true);
+/// A HashMap mapping edge indices to their closeness centrality values, or an error message if
+/// `distance_type` is unknown
+pub fn s_closeness_rust(
+    hypergraph: &HypergraphRust,
+    s: f64,
+    distance_type: &str,
+    weighted: bool,
+) -> Result<HashMap<Vec<usize>, f64>, HypergraphError> {
+    let (graph, id_to_edge) = line_graph(hypergraph, distance_type, s, weighted)?;
+    let closeness = if weighted {
+        weighted_closeness_centrality(&graph)
+    } else {
+        closeness_centrality(&graph, true)
+    };
     
-    closeness.into_iter()
+    Ok(closeness.into_iter()
         .enumerate()
         .filter_map(|(k, v)| v.map(|value| (id_to_edge[&k].clone(), value)))
+        .collect())
+}
+
+/// Calculates the closeness centrality of a line graph using `1 / weight` as link lengths.
+/// 
+/// Mirrors `closeness_centrality` with the Wasserman-Faust correction for disconnected graphs.
+fn weighted_closeness_centrality(graph: &Graph<(), f64, Undirected>) -> Vec<Option<f64>> {
+    let node_count = graph.node_count();
+
+    graph.node_indices()
+        .map(|source| {
+            let distances = dijkstra(graph, source, None, |link| 1.0 / *link.weight());
+            let reachable = distances.len();
+            if reachable <= 1 {
+                return Some(0.0);
+            }
+            let total: f64 = distances.values().sum();
+            let closeness = (reachable - 1) as f64 / total;
+            Some(closeness * (reachable - 1) as f64 / (node_count - 1) as f64)
+        })
         .collect()
 }
 
+/// Calculates the normalized betweenness centrality of a line graph using `1 / weight` as link lengths.
+/// 
+/// Brandes' algorithm with Dijkstra searches, normalized like `betweenness_centrality`.
+fn weighted_betweenness_centrality(graph: &Graph<(), f64, Undirected>) -> Vec<Option<f64>> {
+    let node_count = graph.node_count();
+    let mut betweenness = vec![0.0; node_count];
+
+    for source in graph.node_indices() {
+        let s = source.index();
+        let mut stack = Vec::with_capacity(node_count);
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        let mut sigma = vec![0.0; node_count];
+        let mut settled: Vec<Option<f64>> = vec![None; node_count];
+        let mut tentative: Vec<Option<f64>> = vec![None; node_count];
+        let mut heap = BinaryHeap::new();
+
+        sigma[s] = 1.0;
+        tentative[s] = Some(0.0);
+        heap.push(Reverse(HeapEntry(0.0, s)));
+
+        while let Some(Reverse(HeapEntry(dist, v))) = heap.pop() {
+            if settled[v].is_some() {
+                continue;
+            }
+            settled[v] = Some(dist);
+            stack.push(v);
+
+            for link in graph.edges(NodeIndex::new(v)) {
+                let w = if link.source().index() == v { link.target().index() } else { link.source().index() };
+                if settled[w].is_some() {
+                    continue;
+                }
+                let candidate = dist + 1.0 / *link.weight();
+                match tentative[w] {
+                    Some(current) if candidate > current => {}
+                    Some(current) if candidate == current => {
+                        sigma[w] += sigma[v];
+                        predecessors[w].push(v);
+                    }
+                    _ => {
+                        tentative[w] = Some(candidate);
+                        sigma[w] = sigma[v];
+                        predecessors[w] = vec![v];
+                        heap.push(Reverse(HeapEntry(candidate, w)));
+                    }
+                }
+            }
+        }
+
+        // Accumuliamo le dipendenze in ordine di distanza decrescente
+        let mut delta = vec![0.0; node_count];
+        while let Some(w) = stack.pop() {
+            for &v in &predecessors[w] {
+                delta[v] += sigma[v] / sigma[w] * (1.0 + delta[w]);
+            }
+            if w != s {
+                betweenness[w] += delta[w];
+            }
+        }
+    }
+
+    let scale = if node_count > 2 { 1.0 / ((node_count - 1) * (node_count - 2)) as f64 } else { 1.0 };
+    betweenness.into_iter().map(|value| Some(value * scale)).collect()
+}
+
+/// Entry of the Dijkstra priority queue, ordered by distance.
+#[derive(PartialEq)]
+struct HeapEntry(f64, usize);
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then_with(|| self.1.cmp(&other.1))
+    }
+}
+
+/// Calculates the s-connected components of a hypergraph.
+/// 
+/// Two edges are s-adjacent when they share at least `s` nodes, and s-connected when they are linked
+/// by a chain of s-adjacent edges.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `s` - Minimum intersection size for two edges to be adjacent
+/// 
+/// # Returns
+/// The components as groups of edges. Edges are sorted within each component, and components are
+/// sorted by decreasing number of edges, ties broken by their edges.
+pub fn s_connected_components_rust(hypergraph: &HypergraphRust, s: usize) -> Result<Vec<Vec<Vec<usize>>>, HypergraphError> {
+    let (graph, id_to_edge) = line_graph(hypergraph, "intersection", s as f64, false)?;
+
+    let mut components: Vec<Vec<Vec<usize>>> = connected_components(&graph)
+        .into_iter()
+        .map(|component| {
+            let mut edges: Vec<Vec<usize>> = component
+                .into_iter()
+                .map(|node| id_to_edge[&node.index()].clone())
+                .collect();
+            edges.sort_unstable();
+            edges
+        })
+        .collect();
+
+    components.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    Ok(components)
+}
+
+/// Calculates the overlapping k-clique communities of the s-line graph of a hypergraph.
+/// 
+/// The maximal cliques with at least `k` hyperedges are found in the line graph built with the
+/// "intersection" distance and threshold `s`; two cliques are merged when they share `k - 1`
+/// hyperedges, and each community is the union of a group of merged cliques. A hyperedge can belong
+/// to several communities, or to none if it is in no clique of size `k`.
+/// 
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `k` - Size of the cliques, at least 2
+/// * `s` - Minimum intersection size for two edges to be adjacent
+/// 
+/// # Returns
+/// The communities as groups of edges. Edges are sorted within each community, and communities are
+/// sorted by decreasing number of edges, ties broken by their edges.
+pub fn clique_percolation_rust(hypergraph: &HypergraphRust, k: usize, s: usize) -> Result<Vec<Vec<Vec<usize>>>, HypergraphError> {
+    if k < 2 {
+        return Err(HypergraphError::InvalidArgument("k must be at least 2.".to_string()));
+    }
+    let (graph, id_to_edge) = line_graph(hypergraph, "intersection", s as f64, false)?;
+
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); graph.node_count()];
+    for link in graph.edge_references() {
+        let (i, j) = (link.source().index(), link.target().index());
+        neighbors[i].insert(j);
+        neighbors[j].insert(i);
+    }
+
+    let mut cliques = Vec::new();
+    bron_kerbosch(&neighbors, Vec::new(), (0..neighbors.len()).collect(), HashSet::new(), &mut cliques);
+    cliques.retain(|clique| clique.len() >= k);
+
+    // Union-find sulle cricche: si confrontano solo quelle che condividono almeno un nodo
+    let mut parent: Vec<usize> = (0..cliques.len()).collect();
+    let mut cliques_of: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (c, clique) in cliques.iter().enumerate() {
+        for &node in clique {
+            cliques_of.entry(node).or_default().push(c);
+        }
+    }
+    for (c, clique) in cliques.iter().enumerate() {
+        let members: HashSet<usize> = clique.iter().copied().collect();
+        let candidates: HashSet<usize> = clique.iter().flat_map(|node| cliques_of[node].iter().copied()).filter(|&d| d > c).collect();
+        for d in candidates {
+            if cliques[d].iter().filter(|node| members.contains(node)).count() >= k - 1 {
+                let (root_c, root_d) = (find_root(&mut parent, c), find_root(&mut parent, d));
+                parent[root_c] = root_d;
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, HashSet<usize>> = HashMap::new();
+    for (c, clique) in cliques.iter().enumerate() {
+        groups.entry(find_root(&mut parent, c)).or_default().extend(clique.iter().copied());
+    }
+
+    let mut communities: Vec<Vec<Vec<usize>>> = groups
+        .into_values()
+        .map(|group| {
+            let mut edges: Vec<Vec<usize>> = group.into_iter().map(|node| id_to_edge[&node].clone()).collect();
+            edges.sort_unstable();
+            edges
+        })
+        .collect();
+
+    communities.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    Ok(communities)
+}
+
+/// Enumerates the maximal cliques of a graph with the Bron-Kerbosch algorithm with pivoting.
+/// 
+/// # Arguments
+/// * `neighbors` - Adjacency sets of the graph
+/// * `clique` - The clique being grown
+/// * `candidates` - Nodes that can extend `clique`
+/// * `excluded` - Nodes already used to extend `clique` in another branch
+/// * `cliques` - Output vector the maximal cliques are pushed to
+fn bron_kerbosch(
+    neighbors: &[HashSet<usize>],
+    clique: Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique);
+        }
+        return;
+    }
+
+    // Il pivot con più vicini tra i candidati riduce i rami da esplorare
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .copied()
+        .max_by_key(|&u| neighbors[u].iter().filter(|v| candidates.contains(v)).count())
+        .unwrap_or_default();
+    let branches: Vec<usize> = candidates.iter().copied().filter(|v| !neighbors[pivot].contains(v)).collect();
+
+    for v in branches {
+        let mut extended = clique.clone();
+        extended.push(v);
+        bron_kerbosch(
+            neighbors,
+            extended,
+            candidates.iter().copied().filter(|u| neighbors[v].contains(u)).collect(),
+            excluded.iter().copied().filter(|u| neighbors[v].contains(u)).collect(),
+            cliques,
+        );
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
+
+/// Returns the representative of the set containing `x`, halving the path along the way.
+fn find_root(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
 /// Constructs a line graph from a hypergraph based on edge intersections.
 /// 
 /// Creates a graph where nodes represent hyperedges and edges represent 
@@ -48,20 +341,34 @@ pub fn s_closeness_rust(hypergraph: &HypergraphRust, s: f64) -> HashMap<Vec<usiz
 /// 
 /// # Arguments
 /// * `hypergraph` - The input hypergraph
-/// * `distance_type` - Type of distance measure to use ("intersection" or "jaccard")
+/// * `distance_type` - Type of distance measure to use ("intersection", "jaccard" or "dice")
 /// * `s` - Threshold value for edge connectivity
 /// * `weighted` - Whether to use weighted edges in the line graph
 /// 
+/// With the `rayon` feature enabled, the pairwise distances are computed in parallel.
+/// 
 /// # Returns
 /// A tuple containing:
 /// * The line graph as a Graph<(), f64, Undirected>
 /// * A HashMap mapping node indices to their corresponding hyperedge indices
+/// 
+/// or an error message if `distance_type` is unknown.
 pub fn line_graph(
     hypergraph: &HypergraphRust, 
     distance_type: &str,
     s: f64, 
     weighted: bool
-) -> (Graph<(), f64, Undirected>, HashMap<usize, Vec<usize>>) {
+) -> Result<LineGraph, HypergraphError> {
+    let calculate_distance: fn(&HashSet<usize>, &HashSet<usize>) -> f64 = match distance_type {
+        "intersection" => |set1, set2| intersection_rust(set1, set2) as f64,
+        "jaccard" => jaccard_similarity_rust,
+        "dice" => dice_coefficient_rust,
+        _ => return Err(HypergraphError::InvalidArgument(format!(
+            "Unknown distance type '{}', expected 'intersection', 'jaccard' or 'dice'.",
+            distance_type
+        ))),
+    };
+
     let edge_list: Vec<_> = hypergraph.edge_list.keys().collect();
     let num_edges = edge_list.len();
     let mut id_to_edge: HashMap<usize, Vec<usize>> = HashMap::new();
@@ -74,34 +381,36 @@ pub fn line_graph(
         id_to_edge.insert(i, (*edge).clone());
     }
 
-    let calculate_distance = |edge1: &Vec<usize>, edge2: &Vec<usize>| -> f64 {
-        let set1: HashSet<_> = edge1.iter().collect();
-        let set2: HashSet<_> = edge2.iter().collect();
-        
-        match distance_type {
-            "intersection" => set1.intersection(&set2).count() as f64,
-            "jaccard" => {
-                let intersection = set1.intersection(&set2).count() as f64;
-                let union = set1.union(&set2).count() as f64;
-                intersection / union
-            },
-            _ => set1.intersection(&set2).count() as f64, // default to intersection
-        }
-    };
-
-    for i in 0..num_edges {
-        for j in (i + 1)..num_edges {
-            let edge1 = &edge_list[i];
-            let edge2 = &edge_list[j];
-
-            let distance = calculate_distance(edge1, edge2);
+    // Ogni insieme di nodi viene costruito una sola volta
+    let edge_sets: Vec<HashSet<usize>> = edge_list.iter().map(|edge| edge.iter().copied().collect()).collect();
 
+    let connect = |i: usize| {
+        let edge_sets = &edge_sets;
+        ((i + 1)..num_edges).filter_map(move |j| {
+            let distance = calculate_distance(&edge_sets[i], &edge_sets[j]);
             if distance >= s {
                 let weight = if weighted { distance } else { 1.0 };
-                graph.add_edge(node_indices[i], node_indices[j], weight);
+                Some((i, j, weight))
+            } else {
+                None
             }
-        }
+        })
+    };
+
+    #[cfg(feature = "rayon")]
+    let links: Vec<(usize, usize, f64)> = {
+        use rayon::prelude::*;
+
+        (0..num_edges).into_par_iter().flat_map_iter(connect).collect()
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let links: Vec<(usize, usize, f64)> = (0..num_edges).flat_map(connect).collect();
+
+    // Il grafo non è thread-safe: gli archi vengono aggiunti in serie
+    for (i, j, weight) in links {
+        graph.add_edge(node_indices[i], node_indices[j], weight);
     }
 
-    (graph, id_to_edge)
+    Ok((graph, id_to_edge))
 }
\ No newline at end of file
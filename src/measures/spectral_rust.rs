@@ -0,0 +1,209 @@
+extern crate nalgebra as na;
+use na::DMatrix;
+use std::collections::{HashMap, HashSet};
+use crate::core::error::HypergraphError;
+use crate::core::hypergraph_rust::HypergraphRust;
+use crate::core::label_encoder::LabelEncoder;
+
+/// Builds the dense weighted two-section adjacency of a hypergraph.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+///
+/// # Returns
+/// * `Ok((LabelEncoder, DMatrix<f64>))` - The encoder mapping node IDs to row indices, and the
+///   symmetric matrix of `HypergraphRust::two_section` weights
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+fn two_section_matrix(hypergraph: &HypergraphRust) -> Result<(LabelEncoder, DMatrix<f64>), HypergraphError> {
+    let encoder = hypergraph.get_mapping()?;
+    let mapping = encoder.get_mapping();
+    let num_nodes = mapping.len();
+
+    let mut adjacency = DMatrix::<f64>::zeros(num_nodes, num_nodes);
+    for ((u, v), weight) in hypergraph.two_section() {
+        let (i, j) = (mapping[&u], mapping[&v]);
+        adjacency[(i, j)] = weight;
+        adjacency[(j, i)] = weight;
+    }
+
+    Ok((encoder, adjacency))
+}
+
+/// Builds the Laplacian of the weighted two-section of a hypergraph.
+///
+/// The combinatorial Laplacian is `L = D - A`, where `A` is the two-section adjacency and `D` the
+/// diagonal matrix of its row sums. The normalized Laplacian is `I - D^{-1/2} A D^{-1/2}`; rows and
+/// columns of isolated nodes are left at zero.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `normalized` - Whether to return the symmetric normalized Laplacian
+///
+/// # Returns
+/// * `Ok((LabelEncoder, DMatrix<f64>))` - The encoder mapping node IDs to row indices, and the Laplacian
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn laplacian_matrix_rust(
+    hypergraph: &HypergraphRust,
+    normalized: bool,
+) -> Result<(LabelEncoder, DMatrix<f64>), HypergraphError> {
+    let (encoder, adjacency) = two_section_matrix(hypergraph)?;
+    let num_nodes = adjacency.nrows();
+    let degrees: Vec<f64> = adjacency.row_iter().map(|row| row.sum()).collect();
+
+    let laplacian = if normalized {
+        let inv_sqrt: Vec<f64> = degrees.iter().map(|&d| if d > 0.0 { 1.0 / d.sqrt() } else { 0.0 }).collect();
+        DMatrix::from_fn(num_nodes, num_nodes, |i, j| {
+            let identity = if i == j && degrees[i] > 0.0 { 1.0 } else { 0.0 };
+            identity - inv_sqrt[i] * adjacency[(i, j)] * inv_sqrt[j]
+        })
+    } else {
+        DMatrix::from_fn(num_nodes, num_nodes, |i, j| {
+            let degree = if i == j { degrees[i] } else { 0.0 };
+            degree - adjacency[(i, j)]
+        })
+    };
+
+    Ok((encoder, laplacian))
+}
+
+/// Returns the eigenvalues of a symmetric matrix in ascending order.
+fn sorted_eigenvalues(matrix: DMatrix<f64>) -> Vec<f64> {
+    let mut eigenvalues: Vec<f64> = matrix.symmetric_eigenvalues().iter().copied().collect();
+    eigenvalues.sort_by(f64::total_cmp);
+    eigenvalues
+}
+
+/// Computes the spectrum of the weighted two-section adjacency of a hypergraph.
+///
+/// The largest eigenvalue bounds the attenuation factors for which Katz-like series converge.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The eigenvalues, in ascending order
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn adjacency_spectrum_rust(hypergraph: &HypergraphRust) -> Result<Vec<f64>, HypergraphError> {
+    let (_, adjacency) = two_section_matrix(hypergraph)?;
+    Ok(sorted_eigenvalues(adjacency))
+}
+
+/// Computes the spectrum of the two-section Laplacian of a hypergraph.
+///
+/// The multiplicity of the eigenvalue 0 is the number of connected components.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `normalized` - Whether to use the normalized Laplacian instead of the combinatorial one
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The eigenvalues, in ascending order
+/// * `Err(HypergraphError)` - Error if the hypergraph has no nodes
+pub fn laplacian_spectrum_rust(hypergraph: &HypergraphRust, normalized: bool) -> Result<Vec<f64>, HypergraphError> {
+    let (_, laplacian) = laplacian_matrix_rust(hypergraph, normalized)?;
+    Ok(sorted_eigenvalues(laplacian))
+}
+
+/// Computes a spectral embedding of the nodes of a hypergraph.
+///
+/// Each node is mapped to its entries in the eigenvectors of the two-section Laplacian associated
+/// with the `dims` smallest eigenvalues after the trivial one. The eigenvectors are computed with
+/// nalgebra's symmetric eigensolver; each one is unit-norm and its sign is fixed so that its largest
+/// entry in absolute value is positive. Within a repeated eigenvalue the basis is arbitrary.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `dims` - Number of coordinates of the embedding, between 1 and the number of nodes minus 1
+/// * `normalized` - Whether to use the normalized Laplacian instead of the combinatorial one
+///
+/// # Returns
+/// * `Ok(HashMap<usize, Vec<f64>>)` - Map of node indices to their `dims` coordinates
+/// * `Err(HypergraphError)` - Error if the hypergraph is empty or not connected (each component
+///   would add another zero eigenvalue), or if `dims` is out of range
+pub fn spectral_embedding_rust(
+    hypergraph: &HypergraphRust,
+    dims: usize,
+    normalized: bool,
+) -> Result<HashMap<usize, Vec<f64>>, HypergraphError> {
+    if hypergraph.edge_list.is_empty() {
+        return Err(HypergraphError::EmptyHypergraph);
+    }
+    if !hypergraph.is_connected_rust() {
+        return Err(HypergraphError::NotConnected.with_message(
+            "The hypergraph is not connected (isolated nodes count as components, see remove_isolated_nodes)."
+        ));
+    }
+    let num_nodes = hypergraph.num_nodes();
+    if dims == 0 || dims >= num_nodes {
+        return Err(HypergraphError::InvalidArgument(format!(
+            "dims must be between 1 and {}, the number of nodes minus 1.", num_nodes - 1
+        )));
+    }
+
+    let (encoder, laplacian) = laplacian_matrix_rust(hypergraph, normalized)?;
+    let eigen = laplacian.symmetric_eigen();
+
+    let mut order: Vec<usize> = (0..num_nodes).collect();
+    order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+
+    // Si scarta il primo autovettore, costante (o proporzionale a D^{1/2} se normalizzato)
+    let mut coordinates = vec![Vec::with_capacity(dims); num_nodes];
+    for &k in order.iter().skip(1).take(dims) {
+        let column = eigen.eigenvectors.column(k);
+        let pivot = column.iter().copied().fold(0.0_f64, |best, x| if x.abs() > best.abs() { x } else { best });
+        let sign = if pivot < 0.0 { -1.0 } else { 1.0 };
+        for (idx, coords) in coordinates.iter_mut().enumerate() {
+            coords.push(sign * column[idx]);
+        }
+    }
+
+    Ok(coordinates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, coords)| encoder.inverse_transform(idx).map(|node| (node, coords)))
+        .collect())
+}
+
+/// Computes the conductance of a node subset on the weighted two-section of a hypergraph.
+///
+/// The conductance is `cut(S, V \ S) / min(vol(S), vol(V \ S))`, where the cut is the total
+/// `HypergraphRust::two_section` weight of the pairs crossing the partition and the volume of a set
+/// is the sum of the weighted two-section degrees of its nodes.
+///
+/// # Arguments
+/// * `hypergraph` - The input hypergraph
+/// * `subset` - Nodes on one side of the partition; duplicates are ignored
+///
+/// # Returns
+/// * `Ok(f64)` - The conductance, between 0 and 1
+/// * `Err(HypergraphError)` - Error if a node is not in the hypergraph, or if either side of the
+///   partition has zero volume
+pub fn conductance_rust(hypergraph: &HypergraphRust, subset: &[usize]) -> Result<f64, HypergraphError> {
+    if let Some(&node) = subset.iter().find(|&&node| !hypergraph.check_node(node)) {
+        return Err(HypergraphError::NodeNotFound(node));
+    }
+    let inside: HashSet<usize> = subset.iter().copied().collect();
+
+    let (mut cut, mut volume_in, mut volume_out) = (0.0, 0.0, 0.0);
+    for ((u, v), weight) in hypergraph.two_section() {
+        // Ogni coppia contribuisce al grado di entrambi gli estremi
+        for node in [u, v] {
+            if inside.contains(&node) {
+                volume_in += weight;
+            } else {
+                volume_out += weight;
+            }
+        }
+        if inside.contains(&u) != inside.contains(&v) {
+            cut += weight;
+        }
+    }
+
+    let volume = f64::min(volume_in, volume_out);
+    if volume <= 0.0 {
+        return Err(HypergraphError::InvalidArgument(
+            "Both sides of the partition must have a positive volume.".to_string(),
+        ));
+    }
+    Ok(cut / volume)
+}
@@ -42,6 +42,12 @@ def print_get_edges(hypergraph = hx.Hypergraph):
     # Assert: verifica che il subgraph sia una istanza di Hypergraph
     # assert isinstance(subgraph, hx.Hypergraph)
 
+def print_get_edges_with_weights(hypergraph = hx.Hypergraph):
+    print(sorted(hypergraph.get_edges_with_weights()))
+    print(hypergraph.get_edges_with_weights(size = 4))
+    # assert : [((1, 2), 1.0), ((2, 3), 2.0), ((2, 3, 5, 6), 3.0), ((3, 4, 5, 6, 8), 1.0), ((4, 6, 7), 1.0)]
+    # assert : [((2, 3, 5, 6), 3.0)]
+
 def print_get_nodes(hypergraph=hx.Hypergraph):
     nodes = hypergraph.get_nodes(metadata = False)
     print(nodes)
@@ -79,12 +85,38 @@ def print_get_weights(hypergraph=hx.Hypergraph):
     # Assert: verifica che il numero di pesi restituiti sia corretto
     # assert weights == [1]
 
+def print_get_weights_up_to(hypergraph=hx.Hypergraph):
+    hypergraph.add_edge(edge=(9,), weight=5)
+    weights = hypergraph.get_weights(order=2, up_to=True)
+    edges = hypergraph.get_edges(order=2, up_to=True)
+    print(len(weights), len(edges))
+    # assert : len(weights) == len(edges) == 4 (the order-0 edge (9,) is included)
+
+def print_get_weights_aligned(hypergraph=hx.Hypergraph):
+    edges = hypergraph.get_edges()
+    weights = hypergraph.get_weights()
+    print(len(edges) == len(weights), all(hypergraph.get_weight(edge=list(edge)) == weight for edge, weight in zip(edges, weights)))
+    # assert : True True
+
 def print_is_uniform(hypergraph=hx.Hypergraph):
     is_uniform = hypergraph.is_uniform()
     print(is_uniform)
     # Assert: verifica che il risultato sia un booleano
     # assert uniform = False
 
+def print_is_uniform_empty():
+    hypergraph = hx.Hypergraph()
+    print(hypergraph.is_uniform())
+    try:
+        hx.cec_centrality(hypergraph, tol = 1e-6, max_iter = 100)
+    except ValueError as e:
+        print(e)
+    try:
+        hx.hec_centrality(hypergraph, tol = 1e-6, max_iter = 100)
+    except ValueError as e:
+        print(e)
+    # assert : False, then two "The hypergraph is empty." errors (no panic)
+
 def print_is_weighted(hypergraph=hx.Hypergraph):
     is_weighted = hypergraph.is_weighted()
     print(is_weighted)
@@ -97,6 +129,14 @@ def print_max_order(hypergraph=hx.Hypergraph):
     # Assert: verifica che l'ordine massimo sia corretto
     # assert max_order == 4
 
+def print_max_order_after_removal():
+    hypergraph = hx.Hypergraph(edge_list=[(1, 2), (2, 3)])
+    hypergraph.add_edge(edge=(1, 2, 3, 4))
+    print(hypergraph.max_order())
+    hypergraph.remove_edge(edge=(1, 2, 3, 4))
+    print(hypergraph.max_order(), hypergraph.max_size())
+    # assert : 3, then 1 2
+
 def print_max_size(hypergraph=hx.Hypergraph):
     max_size = hypergraph.max_size()
     print(max_size)
@@ -109,6 +149,14 @@ def print_num_edges(hypergraph=hx.Hypergraph):
     # Assert: verifica che il numero di edge sia corretto
     # assert num_edges == 5
 
+def print_multiplicity():
+    hypergraph = hx.Hypergraph()
+    for _ in range(3):
+        hypergraph.add_edge(edge=(1, 2))
+    print(hypergraph.multiplicity((1, 2)))
+    print(hypergraph.num_edges(), hypergraph.num_edges(multiplicity=True))
+    # assert : 3.0, then 1 3
+
 def print_num_nodes(hypergraph=hx.Hypergraph):
     num_nodes = hypergraph.num_nodes()
     print(num_nodes)
@@ -125,16 +173,83 @@ def print_get_incident_edges(hypergraph = hx.Hypergraph):
     print(edge)
     # assert : [(2, 3, 5, 6), (3, 4, 5, 6, 8)]
 
+def print_get_incident_edge_ids():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2), (1, 2, 3)])
+    edge_ids = hypergraph.get_incident_edges(node = 1, ids = True)
+    print(edge_ids, [hypergraph.get_meta(obj_id = i)["name"] for i in edge_ids])
+    print(hypergraph.get_incident_edges(node = 1, size = 3, ids = True))
+    for ids in (True, False):
+        try:
+            hypergraph.get_incident_edges(node = 1, size = 0, ids = ids)
+        except ValueError as e:
+            print(e)
+    # assert : [0, 3, 5] ['[3, 1]', '[1, 2]', '[1, 2, 3]']
+    # assert : [5]
+    # assert : "Size must be at least 1." twice (no panic)
+
+def print_get_incident_weights(hypergraph = hx.Hypergraph):
+    edges = hypergraph.get_incident_edges(node = 5)
+    weights = hypergraph.get_incident_weights(node = 5)
+    print(list(zip(edges, weights)))
+    # assert : [([2, 3, 5, 6], 3.0), ([3, 4, 5, 6, 8], 1.0)]
+
 def print_get_neighbors(hypergraph = hx.Hypergraph):
     neighbors = hypergraph.get_neighbors(node= 8)
     print(neighbors)
-    # assert : {3, 4, 5, 6}
+    # assert : [3, 4, 5, 6]
+
+def print_common_neighbors(hypergraph = hx.Hypergraph):
+    print(hypergraph.common_neighbors(u = 2, v = 8))
+    # assert : [3, 5, 6]
+    try:
+        hypergraph.common_neighbors(u = 2, v = 100)
+    except ValueError as e:
+        print(e)
+    # assert : Node 100 not found in hypergraph.
+
+def print_co_occurrence(hypergraph = hx.Hypergraph):
+    print(hypergraph.co_occurrence(u = 3, v = 5))
+    print(hypergraph.co_occurrence(u = 3, v = 5, weighted = True))
+    # assert : 2.0 4.0
+
+def print_edges_containing(hypergraph = hx.Hypergraph):
+    print(hypergraph.edges_containing(nodes = [3, 5]))
+    print(hypergraph.edges_containing(nodes = [1, 8]))
+    # assert : [[2, 3, 5, 6], [3, 4, 5, 6, 8]] []
 
 def print_get_mapping(hypergraph = hx.Hypergraph):
     encoder = hypergraph.get_mapping()
     print(encoder.get_mapping())
     # assert : {1: 0, 2: 1, 3: 2, 4: 3, 5: 4, 6: 5, 7: 6, 8: 7}
 
+def print_filter_edges_by_meta():
+    hypergraph = hx.Hypergraph()
+    hypergraph.add_edge([3, 1], None, {"layer": "email"})
+    hypergraph.add_edge([1, 2], None, {"layer": "phone"})
+    hypergraph.add_edge([2, 4, 5], None, {"layer": "email"})
+    print(hypergraph.filter_edges_by_meta(key = "layer", value = "email"))
+    print(hypergraph.filter_edges_by_meta(key = "layer", value = "sms"))
+    # assert : [[1, 3], [2, 4, 5]] []
+
+def print_nodes_with_meta():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2), (2, 4, 5)])
+    hypergraph.set_meta(obj_id = 1, metadata = {"type": "node", "name": "1", "role": "hub"})
+    print(hypergraph.nodes_with_meta(key = "role", value = "hub"))
+    print(hypergraph.nodes_with_meta(key = "type", value = "node"))
+    # assert : [1] [1, 2, 3, 4, 5]
+
+def print_get_all_node_metadata():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2)])
+    metadata = hypergraph.get_all_node_metadata()
+    print(sorted(metadata.keys()), metadata[3])
+    # assert : [1, 2, 3] {'type': 'node', 'name': '3'}
+
+def print_get_adjacency():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2), (1, 2, 3)])
+    hypergraph.add_node(9)
+    print(sorted(hypergraph.get_adjacency().items()))
+    # assert : [(1, [0, 3, 5]), (2, [3, 5]), (3, [0, 5]), (9, [])]
+
 # SET
 
 def print_set_meta(hypergraph = hx.Hypergraph):
@@ -143,11 +258,56 @@ def print_set_meta(hypergraph = hx.Hypergraph):
     # assert : prova
 
 
+def print_set_attr_json():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3, 4)])
+    hypergraph.set_attr_json(obj_id = 0, attributes = {"time": 12, "confidence": 0.5, "tags": ["email", "work"], "active": True})
+    attributes = hypergraph.get_attr_json(obj_id = 0)
+    print(sorted(attributes.items()))
+    print(hypergraph.get_meta(obj_id = 0))
+    # assert : [('active', True), ('confidence', 0.5), ('name', '[1, 2]'), ('tags', ['email', 'work']), ('time', 12), ('type', 'edge')]
+    # assert : {'name': '[1, 2]', 'type': 'edge'} (i metadati stringa restano invariati)
+
 def print_set_weight(hypergraph = hx.Hypergraph):
     hypergraph.set_weight(edge = (1,2), weight=3)
     print(hypergraph.get_weight(edge=(1,2)))
     # assert : 3
 
+def print_set_weight_vec():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3, 4)])
+    hypergraph.set_weight_vec(edge = (2, 1), weights = [1.0, 0.9, 12.0])
+    print(hypergraph.get_weight_vec(edge = (1, 2)), hypergraph.get_weight_vec(edge = (2, 3, 4)))
+    try:
+        hypergraph.set_weight_vec(edge = (2, 3, 4), weights = [1.0])
+    except ValueError as e:
+        print(e)
+    # assert : [1.0, 0.9, 12.0] None
+    # assert : Weight vectors must all have length 3, got 1.
+
+def print_weight_vec_carry_over():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (1, 3), (3, 4)])
+    hypergraph.set_weight_vec(edge = (2, 3), weights = [1.0, 2.0])
+    hypergraph.set_weight_vec(edge = (1, 3), weights = [0.5, 0.5])
+    print(hypergraph.subhypergraph(nodes = [1, 2, 3]).get_weight_vec(edge = (2, 3)), hypergraph.star(node = 2).get_weight_vec(edge = (2, 3)))
+    hypergraph.contract_nodes(nodes = [1, 2], into = 1)
+    print(hypergraph.get_weight_vec(edge = (1, 3)))
+    # assert : [1.0, 2.0] [1.0, 2.0]
+    # assert : [1.5, 2.5] ((2, 3) si unisce a (1, 3) e i vettori si sommano)
+
+def print_normalize_weights(hypergraph = hx.Hypergraph):
+    hypergraph.normalize_weights()
+    print(sum(hypergraph.get_weights()), hypergraph.total_weight())
+    # assert : 1.0 1.0 (up to rounding)
+
+def print_collapse_edges():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 1), (1, 2), (2, 3)])
+    hypergraph.collapse_edges(aggregate = "sum")
+    print(hypergraph.is_weighted(), sorted(hypergraph.get_edges_with_weights()))
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 1), (1, 2), (2, 3)])
+    hypergraph.collapse_edges(aggregate = "max")
+    print(sorted(hypergraph.get_edges_with_weights()))
+    # assert : True [((1, 2), 3.0), ((2, 3), 1.0)]
+    # assert : [((1, 2), 1.0), ((2, 3), 1.0)]
+
 # CHECK
 
 def print_check_edge(hypergraph = hx.Hypergraph):
@@ -168,6 +328,21 @@ def print_add_edge(weighted = weighted):
     print(hypergraph.get_edges())
     # assert : [(22, 33)]
 
+def print_add_edge_repeated_nodes():
+    hypergraph = hx.Hypergraph()
+    hypergraph.add_edge(edge=(1, 1, 2))
+    print(hypergraph.get_edges(), hypergraph.get_orders())
+    print(hypergraph.check_edge((1, 2, 1)))
+    # assert : [[1, 2]] [1], then True
+
+def print_add_empty_edge():
+    hypergraph = hx.Hypergraph()
+    try:
+        hypergraph.add_edge(edge=())
+    except ValueError as e:
+        print(e)
+    # assert : "Empty edge not allowed." (no panic)
+
 def print_add_edges():
     hypergraph = hx.Hypergraph(weighted=True)
     edges = [(1,2,3),(2,4,5,6),(5,6,7),(1,3),(1,7,6,4)]
@@ -176,6 +351,33 @@ def print_add_edges():
     print(hypergraph.get_edges())
     # assert : [(1,2,3),(2,4,5,6),(5,6,7),(1,3),(1,7,6,4)]
 
+def print_add_edges_iter():
+    hypergraph = hx.Hypergraph(weighted=True)
+    edges = ((1, 2, 3), (2, 4, 5, 6), (5, 6, 7))
+    hypergraph.add_edges_iter(edges=(edge for edge in edges), weights=iter([1, 2, 4]))
+    print(sorted(hypergraph.get_edges()), sorted(hypergraph.get_weights()))
+    try:
+        hypergraph.add_edges_iter(edges=iter([(8, 9), (9, 10)]), weights=iter([1.0]))
+    except ValueError as e:
+        print(e)
+    # assert : [[1, 2, 3], [2, 4, 5, 6], [5, 6, 7]] [1.0, 2.0, 4.0], then "The number of edges and weights must be the same."
+
+def print_with_capacity():
+    hypergraph = hx.Hypergraph.with_capacity(num_nodes = 7, num_edges = 3, weighted = True)
+    for edge, weight in [((1, 2, 3), 1.0), ((3, 4), 2.0), ((5, 6, 7), 0.5)]:
+        hypergraph.add_edge(edge = edge, weight = weight)
+    print(hypergraph.num_nodes(), hypergraph.num_edges(), hypergraph.is_weighted())
+    # assert : 7 3 True
+
+def print_invalid_construction():
+    for edges, weights in [([(1, 2), ()], None), ([(1, 2), (2, 3)], [1.0])]:
+        try:
+            hx.Hypergraph(edge_list = edges, weighted = True, weights = weights)
+            print("no error")
+        except ValueError as e:
+            print(e)
+    # assert : "Empty edge not allowed." e "The number of edges and weights must be the same.", nessun crash
+
 def print_add_node():
     hypergraph = hx.Hypergraph()
     hypergraph.add_node(node= 2)
@@ -195,27 +397,114 @@ def print_remove_edge(hypergraph = hx.Hypergraph):
     hypergraph.remove_edge(edge = (2, 3, 5, 6))
     print(hypergraph.get_edges())
 
+def print_remove_edge_keeps_nodes():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (3, 4), (2, 3)])
+    hypergraph.remove_edge(edge = [3, 4])
+    print(sorted(hypergraph.get_nodes(metadata = False)), hypergraph.is_connected())
+    hypergraph.remove_isolated_nodes()
+    print(sorted(hypergraph.get_nodes(metadata = False)), hypergraph.is_connected())
+    # assert : [1, 2, 3, 4] False (node 4 stays as an isolated node)
+    # assert : [1, 2, 3] True
+
 def print_remove_edges(hypergraph = hx.Hypergraph):
     edge_list = [(1, 2), (2, 3)]
     hypergraph.remove_edges(edges = edge_list)
     print(hypergraph.get_edges())
 
+def print_restrict_to_sizes():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (1, 2, 3), (3, 4, 5, 6)])
+    hypergraph.restrict_to_sizes(sizes = [3, 4])
+    print(sorted(hypergraph.get_edges()), hypergraph.max_order(), sorted(hypergraph.get_neighbors(node = 2)))
+    # assert : [[1, 2, 3], [3, 4, 5, 6]] 3 [1, 3]
+
+def print_meta_counts_after_removal():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6)])
+    hypergraph.remove_edges(edges = [(1, 2), (2, 3)])
+    print(hypergraph.num_edges(), hypergraph.num_edges_meta(), hypergraph.num_nodes(), hypergraph.num_nodes_meta())
+    hypergraph.remove_node(node = 3, keep_edges = True)
+    print(hypergraph.num_edges(), hypergraph.num_edges_meta(), hypergraph.num_nodes(), hypergraph.num_nodes_meta())
+    # assert : 3 3 8 8
+    # assert : 3 3 7 7
+
 def print_remove_node(hypergraph = hx.Hypergraph, keep_edges = bool):
     hypergraph.remove_node(node = 3, keep_edges = keep_edges)
     print(hypergraph.get_nodes(metadata = False))
 
+def print_remove_node_keep_edges(hypergraph = hx.Hypergraph):
+    hypergraph.remove_node(node = 3, keep_edges = True)
+    print(sorted(hypergraph.get_edges()))
+    print(hypergraph.get_incident_edges(node = 5), hypergraph.check_node(3))
+    # assert : [[1, 2], [2], [2, 5, 6], [4, 5, 6, 8], [4, 6, 7]]
+    # assert : [[2, 5, 6], [4, 5, 6, 8]] False
+
+def print_remove_node_keep_edges_collision():
+    removed = hx.Hypergraph(edge_list = [(1, 2), (1, 2, 3)], weighted = True, weights = [1.0, 5.0])
+    contracted = removed.copy()
+    removed.remove_node(node = 3, keep_edges = True)
+    contracted.contract_nodes(nodes = [2, 3], into = 2)
+    print(removed.get_edges_with_weights(), contracted.get_edges_with_weights())
+    # assert : [((1, 2), 6.0)] [((1, 2), 6.0)] (the weights of the colliding edges are summed)
+
 def print_remove_nodes(hypergraph = hx.Hypergraph, keep_edges = bool):
     nodes = [6,7]
     print(hypergraph.get_nodes(metadata = False))
     hypergraph.remove_nodes(nodes = nodes, keep_edges = keep_edges)
     print(hypergraph.get_nodes(metadata = False))
 
+def print_prune_by_weight(hypergraph = hx.Hypergraph):
+    hypergraph.prune_by_weight(min_weight = 2.0, remove_isolated = True)
+    print(sorted(hypergraph.get_edges()))
+    print(sorted(hypergraph.get_nodes(metadata = False)))
+    # assert : [[2, 3], [2, 3, 5, 6]] [2, 3, 5, 6]
+
+def print_contract_nodes():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (1, 3), (3, 4, 5), (1, 2, 6)])
+    hypergraph.contract_nodes(nodes = [1, 2], into = 1)
+    print(sorted(hypergraph.get_edges_with_weights()), sorted(hypergraph.get_nodes(metadata = False)))
+    print(hx.degree(hypergraph, 1), sorted(hypergraph.get_sizes()))
+    # assert : [((1, 3), 2.0), ((1, 6), 1.0), ((3, 4, 5), 1.0)] [1, 3, 4, 5, 6]
+    # assert : 2 [2, 2, 3] ((1, 2) viene scartato, (2, 3) si unisce a (1, 3))
+
+def print_double_edge_swap():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6), (1, 9, 10), (5, 9)])
+    degrees = hx.degree_sequence(hypergraph)
+    sizes = sorted(hypergraph.get_sizes())
+    swaps = hypergraph.double_edge_swap(num_swaps = 20, seed = 7)
+    print(swaps, hx.degree_sequence(hypergraph) == degrees, sorted(hypergraph.get_sizes()) == sizes)
+    # assert : 20 True True
+
+def print_isolated_nodes(hypergraph = hx.Hypergraph):
+    hypergraph.remove_edge(edge = (1, 2))
+    print(hypergraph.isolated_nodes())
+    hypergraph.remove_isolated_nodes()
+    print(hypergraph.isolated_nodes(), hypergraph.check_node(1))
+    # assert : [1], then [] False
+
 # OTHER
 
 def print_distribution_sizes(hypergraph = hx.Hypergraph):
     print(hypergraph.distribution_sizes())
     #assert : {2: 2, 5: 1, 4: 1, 3: 1}
 
+def print_distribution_sizes_after_mutations():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6)])
+    fresh = lambda: {size: [len(edge) for edge in hypergraph.get_edges()].count(size) for size in set(hypergraph.get_sizes())}
+    checks = [hypergraph.distribution_sizes() == fresh()]
+    hypergraph.add_edge(edge = (9, 10, 11))
+    checks.append(hypergraph.distribution_sizes() == fresh())
+    hypergraph.remove_edge((2, 3))
+    checks.append(hypergraph.distribution_sizes() == fresh())
+    hypergraph.remove_node(6)
+    checks.append(hypergraph.distribution_sizes() == fresh())
+    hypergraph.contract_nodes([9, 10], 9)
+    checks.append(hypergraph.distribution_sizes() == fresh())
+    print(checks, hypergraph.distribution_sizes())
+    # assert : [True, True, True, True, True] {2: 2}
+
+def print_distribution_orders(hypergraph = hx.Hypergraph):
+    print(hypergraph.distribution_orders())
+    # assert : {1: 2, 4: 1, 3: 1, 2: 1}
+
 def print_subhypergraph(hypergraph = hx.Hypergraph):
     nodes = [1, 2, 4]
     subhy = hypergraph.subhypergraph(nodes = nodes)
@@ -223,51 +512,590 @@ def print_subhypergraph(hypergraph = hx.Hypergraph):
     print(subhy.get_edges())
     # assert : node [1, 2, 4] edge [(1, 2)]
 
+def print_subhypergraph_isolated_and_multiplicity():
+    hypergraph = hx.Hypergraph(edge_list = [(10, 20), (20, 30), (10, 20)])
+    hypergraph.add_node(99)
+    subhy = hypergraph.subhypergraph(nodes = [10, 20, 99])
+    print(sorted(subhy.get_nodes(metadata = True)), subhy.get_edges_with_weights())
+    # assert : [(10, {'type': 'node', 'name': '10'}), (20, {'type': 'node', 'name': '20'}), (99, {'type': 'node', 'name': '99'})] [((10, 20), 2.0)] (il nodo isolato resta, la molteplicità è conservata)
+
+def print_subhypergraph_contained_edges(hypergraph = hx.Hypergraph):
+    subhy = hypergraph.subhypergraph(nodes = [1, 2, 3, 5, 6])
+    print(sorted(subhy.get_edges()))
+    print(subhy.get_weight(edge = (2, 3, 5, 6)))
+    # assert : [[1, 2], [2, 3], [2, 3, 5, 6]] (edges with 4, 7 or 8 are dropped), weight 3.0
+
 def print_subhypergraph_by_order(hypergraph = hx.Hypergraph):
     orders = [2, 3]
     subhy = hypergraph.subhypergraph_by_orders(orders=orders, keep_nodes=True)
     print(subhy.get_nodes(metadata = False))
     print(subhy.get_edges())
 
+def print_subhypergraph_by_edges():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2), (2, 4, 5)], weighted = True, weights = [1.5, 2.0, 3.0])
+    subhy = hypergraph.subhypergraph_by_edges(edge_ids = [0, 5])
+    print(subhy.get_edges_with_weights(), sorted(subhy.get_nodes(metadata = False)))
+    # assert : [((1, 3), 1.5), ((2, 4, 5), 3.0)] [1, 2, 3, 4, 5]
+
+def print_clique_expansion():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2, 3), (2, 3), (3, 4)], weighted = True, weights = [1.0, 2.0, 0.5])
+    expansion = hypergraph.clique_expansion()
+    print(expansion.is_weighted(), sorted(expansion.get_edges_with_weights()))
+    # assert : True [((1, 2), 1.0), ((1, 3), 1.0), ((2, 3), 3.0), ((3, 4), 0.5)]
+
+def print_star_expansion():
+    hypergraph = hx.Hypergraph(edge_list = [(2, 3), (1, 2, 3)])
+    expansion = hypergraph.star_expansion()
+    print(sorted(expansion.get_edges()))
+    print(expansion.nodes_with_meta(key = "edge", value = "[2, 3]"))
+    # assert : [[1, 4], [2, 4], [2, 5], [3, 4], [3, 5]] (arco-nodi 4 = [1, 2, 3], 5 = [2, 3])
+    # assert : [5]
+
+def print_star_expansion_multiplicity():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (1, 2), (2, 3)])
+    expansion = hypergraph.star_expansion()
+    print(sorted(expansion.get_edges_with_weights()))
+    # assert : [((1, 4), 2.0), ((2, 4), 2.0), ((2, 5), 1.0), ((3, 5), 1.0)] (l'arco ripetuto [1, 2] passa la molteplicità 2)
+
+def print_star(hypergraph = hx.Hypergraph):
+    star = hypergraph.star(node = 5)
+    print(sorted(star.get_nodes(metadata = False)))
+    print(star.get_edges(), star.get_weight(edge = (2, 3, 5, 6)))
+    # assert : nodi [2, 3, 4, 5, 6, 8], archi [[2, 3, 5, 6], [3, 4, 5, 6, 8]] peso 3.0
+    try:
+        hypergraph.star(node = 100)
+    except ValueError as e:
+        print(e)
+    # assert : Node 100 not found in hypergraph.
+
+def print_simplicial_closure(hypergraph = hx.Hypergraph):
+    closure = hypergraph.simplicial_closure()
+    print(hypergraph.is_simplicial(), closure.is_simplicial())
+    print(closure.distribution_sizes())
+    # assert : False True
+    # assert : {2: 16, 3: 14, 4: 6, 5: 1}
+
+def print_maximal_edges():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2, 3), (1, 2), (2, 3), (3, 4), (2, 3, 4, 5)])
+    print(hypergraph.maximal_edges())
+    # assert : [[1, 2, 3], [2, 3, 4, 5]]
+
+def print_wl_hash():
+    hypergraph_a = hx.Hypergraph(edge_list = [(1, 2, 3), (3, 4), (4, 5, 6, 7), (1, 5)])
+    hypergraph_b = hx.Hypergraph(edge_list = [(10, 20, 30), (30, 40), (40, 50, 60, 70), (10, 50)])
+    hypergraph_c = hx.Hypergraph(edge_list = [(1, 2, 3), (3, 4), (4, 5, 6, 7), (3, 5)])
+    print(hypergraph_a.wl_hash() == hypergraph_b.wl_hash(), hypergraph_a.wl_hash() == hypergraph_c.wl_hash())
+    # assert : True False
+
+def print_is_isomorphic():
+    hexagon = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)])
+    triangles = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4)])
+    relabeled = hx.Hypergraph(edge_list = [(10, 30), (30, 20), (20, 50), (50, 40), (40, 60), (60, 10)])
+    print(hexagon.is_isomorphic(relabeled), hexagon.is_isomorphic(triangles))
+    # assert : True False
+
+def print_incidence_scipy():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2, 5), (2, 5)])
+    print(hypergraph.incidence_scipy())
+    # assert : ([1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0], [0, 1, 0, 2, 1, 0, 2], [0, 2, 4, 5, 7], (4, 3))
+
+def print_from_incidence_matrix():
+    hypergraph = hx.Hypergraph(edge_list = [(3, 1), (1, 2, 5), (2, 5)])
+    data, indices, indptr, (num_nodes, num_edges) = hypergraph.incidence_scipy()
+    matrix = [[0] * num_edges for _ in range(num_nodes)]
+    for row in range(num_nodes):
+        for k in range(indptr[row], indptr[row + 1]):
+            matrix[row][indices[k]] = 1
+    rebuilt = hx.Hypergraph.from_incidence_matrix(matrix, sorted(hypergraph.get_nodes(metadata = False)))
+    print(sorted(rebuilt.get_edges_with_weights()) == sorted(hypergraph.get_edges_with_weights()))
+    print(sorted(hx.Hypergraph.from_incidence_matrix([[1, 0], [1, 1], [0, 1], [0, 0]], [10, 20, 30, 40], weighted_values = [0.5, 2.0]).get_edges_with_weights()))
+    print(hx.Hypergraph.from_incidence_matrix([[1, 1], [1, 1]], [1, 2], weighted_values = [0.5, 2.0]).get_edges_with_weights(), hx.Hypergraph.from_incidence_matrix([[1, 1], [1, 1]], [1, 2]).get_edges_with_weights())
+    try:
+        hx.Hypergraph.from_incidence_matrix([[1, 0], [1, 0]], [1, 2])
+    except ValueError as e:
+        print(e)
+    # assert : True
+    # assert : [((10, 20), 0.5), ((20, 30), 2.0)] (node 40 is kept as an isolated node)
+    # assert : [((1, 2), 2.5)] [((1, 2), 2.0)] (colonne identiche: pesi sommati, o molteplicità 2)
+    # assert : Column 1 of the incidence matrix has no nonzero entry.
+
+def print_from_adjacency_matrix(hypergraph = hx.Hypergraph):
+    nodes, matrix = hypergraph.adjacency_matrix()
+    print(hx.Hypergraph.from_adjacency_matrix(matrix, nodes).adjacency_matrix() == (nodes, matrix))
+    print(sorted(hx.Hypergraph.from_adjacency_matrix([[0, 0.5, 2.0], [0.5, 0, 0], [2.0, 0, 0]], [7, 8, 9], threshold = 1.0).get_edges_with_weights()))
+    try:
+        hx.Hypergraph.from_adjacency_matrix([[0, 1], [2, 0]], [1, 2])
+    except ValueError as e:
+        print(e)
+    # assert : True
+    # assert : [((7, 9), 2.0)] (node 8 is kept as an isolated node)
+    # assert : The adjacency matrix is not symmetric at (0, 1).
+
+def print_temporal_hypergraph():
+    temporal = hx.TemporalHypergraph(edge_list = [(0, [1, 2]), (0, [2, 1]), (1, [1, 2]), (3, [2, 3, 4])])
+    print(temporal.time_range(), temporal.get_edges_at(time = 0), temporal.get_edges_at(time = 2))
+    print(sorted(temporal.aggregate().get_edges_with_weights()))
+    # assert : (0, 3) [[1, 2]] []
+    # assert : [((1, 2), 3.0), ((2, 3, 4), 1.0)]
+
+def print_temporal_slice():
+    temporal = hx.TemporalHypergraph(edge_list = [(0, [1, 2]), (1, [1, 2]), (1, [2, 3]), (3, [2, 3, 4])])
+    print(sorted(temporal.slice(t_start = 1, t_end = 3).get_edges_with_weights()))
+    print(sorted(temporal.slice(t_start = 0, t_end = 1).get_edges_with_weights()))
+    # assert : [((1, 2), 1.0), ((2, 3), 1.0), ((2, 3, 4), 1.0)]
+    # assert : [((1, 2), 2.0), ((2, 3), 1.0)]
+
+def print_directed_hypergraph():
+    directed = hx.DirectedHypergraph(edge_list = [([1, 2], [3]), ([3], [4, 5]), ([2, 1], [3])])
+    directed.add_edge(tail = [4], head = [1], metadata = {"kind": "feedback"})
+    print(directed.get_edges(), directed.get_weight(tail = [1, 2], head = [3]))
+    print([(node, directed.in_degree(node), directed.out_degree(node)) for node in directed.get_nodes()])
+    # assert : [([1, 2], [3]), ([3], [4, 5]), ([4], [1])] 2.0
+    # assert : [(1, 1, 1), (2, 0, 1), (3, 1, 1), (4, 1, 1), (5, 1, 0)]
+
+def print_forward_reachable():
+    # Rete di reazioni: A + B -> C, C -> D, D + E -> F, B -> E
+    reactions = hx.DirectedHypergraph(edge_list = [([1, 2], [3]), ([3], [4]), ([4, 5], [6]), ([2], [5])])
+    print(sorted(reactions.forward_reachable(source = 1)))
+    print(sorted(reactions.forward_reachable(source = 2)))
+    reactions.add_edge(tail = [2], head = [1])
+    print(sorted(reactions.forward_reachable(source = 2)))
+    # assert : [1] (A da solo non attiva A + B -> C)
+    # assert : [2, 5]
+    # assert : [1, 2, 3, 4, 5, 6]
+
+# MEASURES
+
+def print_centralities_non_contiguous_ids():
+    hypergraph = hx.Hypergraph(edge_list = [(10, 20), (20, 30), (10, 30)])
+    print(hx.cec_centrality(hypergraph, tol = 1e-6, max_iter = 1000))
+    print(hx.zec_centrality(hypergraph, tol = 1e-6, max_iter = 1000))
+    print(hx.hec_centrality(hypergraph, tol = 1e-6, max_iter = 1000))
+    # assert : keys {10, 20, 30} for all three, no panic
+
+def print_cec_centrality_general(hypergraph = hx.Hypergraph):
+    print(hx.cec_centrality_general(hypergraph, tol = 1e-6, max_iter = 1000))
+    # assert : one value per node, even though the hypergraph is not uniform
+
+def print_cec_adjacency():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2, 3), (2, 3, 4), (3, 4, 5), (1, 5, 6), (1, 2, 3)])
+    nodes, matrix = hypergraph.adjacency_matrix()
+    x = [1.0] * len(nodes)
+    for _ in range(1000):
+        y = [sum(a * b for a, b in zip(row, x)) for row in matrix]
+        norm = sum(value ** 2 for value in y) ** 0.5
+        x = [value / norm for value in y]
+    cec = hx.cec_centrality(hypergraph, tol = 1e-12, max_iter = 10000)
+    print(matrix[0], all(abs(cec[node] - value) < 1e-9 for node, value in zip(nodes, x)))
+    # assert : [0.0, 2.0, 2.0, 0.0, 1.0, 1.0] True (the repeated edge (1, 2, 3) counts twice)
+
+def print_centrality_diagnostics():
+    hypergraph = hx.Hypergraph(edge_list = [(10, 20), (20, 30), (10, 30)])
+    cec, info = hx.cec_centrality(hypergraph, tol = 1e-6, max_iter = 1000, diagnostics = True)
+    print(cec, info)
+    # assert : info == {'iterations': ..., 'residual': ..., 'converged': True}
+    hec, info = hx.hec_centrality(hypergraph, tol = 1e-12, max_iter = 3, diagnostics = True)
+    print(hec, info)
+    # assert : info['converged'] == False, no exception raised
+
+def print_top_k():
+    scores = {5: 0.2, 1: 0.9, 3: 0.5, 2: 0.5, 4: 0.1}
+    print(hx.top_k(scores, k = 3))
+    # assert : [(1, 0.9), (2, 0.5), (3, 0.5)]
+
+def print_normalize_centrality(hypergraph = hx.Hypergraph):
+    cec = hx.cec_centrality_general(hypergraph, tol = 1e-6, max_iter = 1000)
+    print(max(hx.normalize_centrality(cec).values()))
+    print(sum(hx.normalize_centrality(cec, mode = "l1").values()))
+    # assert : 1.0 1.0
+
+def print_communicability(hypergraph = hx.Hypergraph):
+    communicability = hx.communicability(hypergraph)
+    print({node: round(value, 4) for node, value in sorted(communicability.items())})
+    # assert : {1: 1.8567, 2: 10.2262, 3: 18.4677, 4: 17.0721, 5: 18.4677, 6: 21.3487, 7: 4.8122, 8: 14.4388}
+
+def print_harmonic_centrality():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6), (10, 11)])
+    harmonic = hx.harmonic_centrality(hypergraph)
+    print({node: round(value, 4) for node, value in sorted(harmonic.items())})
+    # assert : {1: 3.5, 2: 5.5, 3: 6.0, 4: 5.8333, 5: 6.0, 6: 6.5, 7: 4.3333, 8: 5.3333, 10: 1.0, 11: 1.0}
+
+def print_spectral_embedding(hypergraph = hx.Hypergraph):
+    embedding = hx.spectral_embedding(hypergraph, dims = 2)
+    print(sorted(embedding) == sorted(hypergraph.get_nodes(metadata = False)), {len(coords) for coords in embedding.values()})
+    print({node: [round(x, 4) for x in coords] for node, coords in sorted(hx.spectral_embedding(hypergraph, dims = 1, normalized = False).items())})
+    try:
+        hx.spectral_embedding(hx.Hypergraph(edge_list = [(1, 2), (3, 4)]))
+    except ValueError as e:
+        print(e)
+    # assert : True {2}
+    # assert : {1: 0.9151, 2: -0.0128, 3: -0.087, 4: -0.1692, 5: -0.0963, 6: -0.1107, 7: -0.284, 8: -0.1551}
+    # assert : Error computing spectral embedding: The hypergraph is not connected (isolated nodes count as components, see remove_isolated_nodes).
+
+def print_conductance(hypergraph = hx.Hypergraph):
+    print(hx.conductance(hypergraph, [1, 2, 3]), hx.conductance(hypergraph, [4, 7, 8]))
+    try:
+        hx.conductance(hypergraph, [])
+    except ValueError as e:
+        print(e)
+    # assert : 0.5714285714285714 0.6666666666666666 (same as networkx.conductance on the weighted two-section)
+    # assert : Error computing conductance: Both sides of the partition must have a positive volume.
+
+def print_spectra(hypergraph = hx.Hypergraph):
+    print([round(x, 4) for x in hx.adjacency_spectrum(hypergraph)])
+    print([round(x, 4) for x in hx.laplacian_spectrum(hypergraph)])
+    print([round(x, 4) for x in hx.adjacency_spectrum(hx.Hypergraph(edge_list = [(1, 2, 3)]))])
+    print(sum(abs(x) < 1e-9 for x in hx.laplacian_spectrum(hx.Hypergraph(edge_list = [(1, 2), (3, 4, 5)]), normalized = True)))
+    # assert : [-5.3628, -4.2361, -2.9337, -1.3871, -0.0835, 0.2098, 1.6248, 12.1686]
+    # assert : [0.0, 1.014, 2.1686, 4.7776, 6.8352, 15.3894, 18.238, 19.5771]
+    # assert : [-1.0, -1.0, 2.0]
+    # assert : 2 (one zero eigenvalue per connected component)
+
+def print_degree_sequence_matches_serial(hypergraph = hx.Hypergraph):
+    serial = {node: len(hypergraph.get_incident_edges(node = node)) for node in hypergraph.get_nodes(metadata = False)}
+    print(hx.degree_sequence(hypergraph) == serial)
+    # assert : True (with and without the `rayon` feature)
+
+def print_degree_distribution_normalized(hypergraph = hx.Hypergraph):
+    distribution = hx.degree_distribution_normalized(hypergraph)
+    print(distribution, sum(distribution.values()))
+    # assert : {1: 0.375, 2: 0.25, 3: 0.375} 1.0
+
+def print_degree_distribution_cdf(hypergraph = hx.Hypergraph):
+    print(hx.degree_distribution_cdf(hypergraph))
+    # assert : [(1, 1.0), (2, 0.625), (3, 0.375)]
+
+def print_entropies(hypergraph = hx.Hypergraph):
+    print(hypergraph.size_entropy())
+    print(hx.degree_entropy(hypergraph))
+    # assert : 1.3321790402101223 (-(0.4 ln 0.4 + 3 * 0.2 ln 0.2))
+    # assert : 1.0821955300387671 (gradi {1: 3, 2: 2, 3: 3})
+
+def print_degree_mixing_matrix(hypergraph = hx.Hypergraph):
+    degrees, matrix = hx.degree_mixing_matrix(hypergraph)
+    print(degrees)
+    print(matrix)
+    # assert : [1, 2, 3]
+    # assert : [[0.0, 0.09375, 0.125], [0.09375, 0.0625, 0.15625], [0.125, 0.15625, 0.1875]] (come networkx.attribute_mixing_dict sulla 2-sezione)
+
+def print_average_neighbor_degree(hypergraph = hx.Hypergraph):
+    print(sorted(hx.average_neighbor_degree(hypergraph).items()))
+    print(sorted(hx.knn(hypergraph).items()))
+    # assert : [(1, 3.0), (2, 2.25), (3, 2.2), (4, 2.0), (5, 2.4), (6, 2.0), (7, 2.5), (8, 2.5)]
+    # assert : [(1, 2.6666666666666665), (2, 2.2), (3, 2.15)]
+
+def print_weighted_projection_degree(hypergraph = hx.Hypergraph):
+    print({node: hx.weighted_projection_degree(hypergraph, node) for node in sorted(hypergraph.get_nodes(metadata = False))})
+    # assert : {1: 1.0, 2: 12.0, 3: 15.0, 4: 6.0, 5: 13.0, 6: 15.0, 7: 2.0, 8: 4.0}
+
+def print_effective_size(hypergraph = hx.Hypergraph):
+    print({node: round(hx.effective_size(hypergraph, node), 4) for node in sorted(hypergraph.get_nodes(metadata = False))})
+    # assert : {1: 1.0, 2: 2.4, 3: 2.625, 4: 2.3333, 5: 2.2143, 6: 3.5, 7: 1.0, 8: 1.5}
+
+def print_density():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (1, 2, 3), (2, 3, 4)])
+    print(hypergraph.density())
+    # assert : 0.4 (4 archi su C(4, 2) + C(4, 3) = 10)
+
+def print_size_stats():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (1, 2, 3), (2, 3, 4, 5)])
+    print(hypergraph.num_incidences(), hypergraph.size_stats())
+    # assert : 11 (2.75, 0.82915619758885, 2, 4)
+
+def print_edge_similarity_matrix(hypergraph = hx.Hypergraph):
+    edges, matrix = hx.edge_similarity_matrix(hypergraph, metric = "overlap")
+    print(edges)
+    print(matrix[0])
+    # assert : [[1, 2], [2, 3], [2, 3, 5, 6], [3, 4, 5, 6, 8], [4, 6, 7]]
+    # assert : [1.0, 0.5, 0.5, 0.0, 0.0]
+
+def print_overlap_coefficient():
+    print(hx.overlap_coefficient({1, 2, 3}, {2, 3, 4, 5, 6}), hx.overlap_coefficient({1, 2}, {1, 2, 3}), hx.overlap_coefficient(set(), {1}))
+    # assert : 0.6666666666666666 1.0 0.0
+
+def print_dice_coefficient(hypergraph = hx.Hypergraph):
+    print(hx.dice_coefficient({1, 2, 3}, {2, 3, 4, 5, 6}), hx.dice_coefficient(set(), set()))
+    links, id_to_edge = hx.line_graph(hypergraph, distance_type = "dice", s = 0.6, weighted = True)
+    print(sorted(links))
+    # assert : 0.5 0.0
+    # assert : [([2, 3], [2, 3, 5, 6], 0.6666666666666666), ([3, 4, 5, 6, 8], [2, 3, 5, 6], 0.6666666666666666)]
+
+def print_node_similarity(hypergraph = hx.Hypergraph):
+    print(round(hx.node_similarity(hypergraph, 3, 6), 4), round(hx.node_similarity(hypergraph, 5, 6, metric = "jaccard"), 4))
+    nodes, matrix = hx.node_similarity_matrix(hypergraph, metric = "jaccard")
+    print(nodes)
+    print([round(value, 3) for value in matrix[2]])
+    # assert : 0.6667 0.6667
+    # assert : [1, 2, 3, 4, 5, 6, 7, 8]
+    # assert : [0.0, 0.5, 1.0, 0.25, 0.667, 0.5, 0.0, 0.333]
+
+def print_s_connected_components(hypergraph = hx.Hypergraph):
+    print(hx.s_connected_components(hypergraph, s = 1))
+    print(hx.s_connected_components(hypergraph, s = 2))
+    # assert : s=1 un'unica componente con tutti gli archi
+    # assert : s=2 [[[2, 3], [2, 3, 5, 6], [3, 4, 5, 6, 8], [4, 6, 7]], [[1, 2]]]
+
+def print_clique_percolation():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2, 3), (2, 3, 4), (3, 4, 1), (5, 6, 7), (6, 7, 8), (7, 8, 5), (3, 5)])
+    print(hx.clique_percolation(hypergraph, k = 3, s = 2))
+    print(hx.clique_percolation(hypergraph, k = 3, s = 1))
+    # assert : [[[1, 2, 3], [1, 3, 4], [2, 3, 4]], [[5, 6, 7], [5, 7, 8], [6, 7, 8]]]
+    # assert : [[[1, 2, 3], [1, 3, 4], [2, 3, 4], [3, 5]], [[3, 5], [5, 6, 7], [5, 7, 8], [6, 7, 8]]] ((3, 5) bridges two overlapping communities)
+
+def print_s_centralities_options(hypergraph = hx.Hypergraph):
+    print(hx.s_betweenness(hypergraph, s = 0.2, distance_type = "jaccard", weighted = True))
+    print(hx.s_closeness(hypergraph, s = 1, distance_type = "intersection", weighted = True))
+    try:
+        hx.s_closeness(hypergraph, s = 1, distance_type = "cosine")
+    except ValueError as e:
+        print(e)
+    # assert : l'ultimo stampa "Error computing s-closeness: Unknown distance type 'cosine', ..."
+
+def print_line_graph(hypergraph = hx.Hypergraph):
+    links, id_to_edge = hx.line_graph(hypergraph, distance_type = "intersection", s = 2, weighted = True)
+    print(sorted(links))
+    print(sorted(id_to_edge.values()))
+    # assert : [([2, 3], [2, 3, 5, 6], 2.0), ([2, 3, 5, 6], [3, 4, 5, 6, 8], 3.0), ([3, 4, 5, 6, 8], [4, 6, 7], 2.0)] (a meno dell'ordine nelle coppie)
+
+# DYNAMICS
+
+def print_sis_contagion(hypergraph = hx.Hypergraph):
+    infected = hx.sis_contagion(hypergraph, beta = 0.5, mu = 0.2, initial_infected = [1], steps = 5, seed = 42)
+    print(infected)
+    print(infected == hx.sis_contagion(hypergraph, beta = 0.5, mu = 0.2, initial_infected = [1], steps = 5, seed = 42))
+    # assert : 6 steps starting from [1], True
+
+def print_threshold_contagion(hypergraph = hx.Hypergraph):
+    active = hx.threshold_contagion(hypergraph, thresholds = {3: 0.9}, initial_active = [1], steps = 10, default_threshold = 0.3)
+    print(active)
+    # assert : [[1], [1, 2]] (fixed point reached)
+
+def print_stationary_distribution(hypergraph = hx.Hypergraph):
+    distribution = hx.stationary_distribution(hypergraph)
+    print({node: round(value, 4) for node, value in sorted(distribution.items())})
+    try:
+        hx.stationary_distribution(hx.Hypergraph(edge_list = [(1, 2), (2, 3)]), max_iter = 50)
+    except ValueError as e:
+        print(e)
+    # assert : {1: 0.0049, 2: 0.1456, 3: 0.2184, 4: 0.0971, 5: 0.2087, 6: 0.2282, 7: 0.0194, 8: 0.0777} (walk pesato da w(e) * (|e| - 1)), then a non-convergence error (periodic walk)
+
+def print_personalized_pagerank():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6)])
+    hypergraph.add_node(9)
+    pagerank = hx.personalized_pagerank(hypergraph, restart_weights = {1: 2.0, 7: 2.0})
+    print({node: round(value, 4) for node, value in sorted(pagerank.items())})
+    try:
+        hx.personalized_pagerank(hypergraph, restart_weights = {42: 1.0})
+    except ValueError as e:
+        print(e)
+    # assert : {1: 0.0845, 2: 0.1225, 3: 0.1504, 4: 0.1312, 5: 0.1417, 6: 0.1891, 7: 0.0972, 8: 0.0834, 9: 0.0}, then a node-not-found error
+
+def print_hitting_time():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6), (10, 11)])
+    print(hx.hitting_time(hx.Hypergraph(edge_list = [(1, 2), (2, 3), (3, 4)]), source = 1, target = 4))
+    print(round(hx.hitting_time(hypergraph, source = 1, target = 7), 4))
+    try:
+        hx.hitting_time(hypergraph, source = 1, target = 10)
+    except ValueError as e:
+        print(e)
+    # assert : 9.0 36.1595, then an unreachable-target error
+
+def print_walk_corpus(hypergraph = hx.Hypergraph):
+    corpus = hx.walk_corpus(hypergraph, num_walks = 3, walk_length = 6, seed = 1)
+    print(len(corpus), all(len(walk) == 6 for walk in corpus), corpus == hx.walk_corpus(hypergraph, num_walks = 3, walk_length = 6, seed = 1))
+    print(all(any(set(step) <= set(edge) for edge in hypergraph.get_edges()) for walk in corpus for step in zip(walk, walk[1:])))
+    # assert : 24 True True
+    # assert : True (consecutive nodes always share an edge)
+
+# GENERATION
+
+def print_sample_edges(hypergraph = hx.Hypergraph):
+    sample = hx.sample_edges(hypergraph, k = 3, seed = 42)
+    print(sample)
+    print(sample == hx.sample_edges(hypergraph, k = 3, seed = 42))
+    # assert : 3 archi distinti, True
+
+def print_bootstrap():
+    hypergraph = hx.Hypergraph(edge_list = [(1, 2), (2, 3), (4, 3, 5, 6, 8), (2, 3, 5, 6), (7, 4, 6), (1, 2), (1, 2)])
+    replicate = hypergraph.bootstrap(seed = 0)
+    print(replicate.num_nodes(), replicate.num_edges(multiplicity = True), sorted(zip(replicate.get_edges(), replicate.get_weights())))
+    again = hypergraph.bootstrap(seed = 0)
+    print(sorted(zip(again.get_edges(), again.get_weights())) == sorted(zip(replicate.get_edges(), replicate.get_weights())))
+    # assert : 8 7 [([1, 2], 3.0), ([2, 3, 5, 6], 1.0), ([3, 4, 5, 6, 8], 3.0)], True
+
+def print_random_hypergraph():
+    random_h = hx.random_hypergraph(num_nodes = 10, num_edges_by_size = {2: 5, 3: 4}, seed = 7)
+    print(random_h.num_nodes(), random_h.distribution_sizes())
+    # assert : 10 {2: 5, 3: 4}
+
+def print_configuration_model():
+    degrees = [2, 2, 1, 1, 2, 1]
+    conf_h = hx.configuration_model(degree_sequence = degrees, edge_size = 3, seed = 3)
+    print(hx.degree_sequence(conf_h))
+    # assert : {0: 2, 1: 2, 2: 1, 3: 1, 4: 2, 5: 1}
+
+def print_chung_lu():
+    expected = [1, 1, 2, 2, 4, 4, 8, 8]
+    chung_lu_h = hx.chung_lu(expected_degrees = expected, num_edges = 10, edge_size = 3, seed = 1)
+    print(chung_lu_h.num_nodes(), chung_lu_h.num_edges(multiplicity = True), set(chung_lu_h.get_sizes()))
+    print(sorted(chung_lu_h.get_edges()) == sorted(hx.chung_lu(expected_degrees = expected, num_edges = 10, edge_size = 3, seed = 1).get_edges()))
+    # assert : 8 10 {3}, then True
+
+def print_hoad_model():
+    activities = {1: [0.5] * 6, 2: [0.2] * 6}
+    hoad_h = hx.hoad_model(num_nodes = 6, activities_per_order = activities, time = 20, seed = 1)
+    print(hoad_h.num_nodes(), hoad_h.is_weighted(), hoad_h.distribution_sizes())
+    # assert : 6 True, solo archi di dimensione 2 e 3
+
+def print_seeded_generators(hypergraph = hx.Hypergraph):
+    runs = [
+        lambda: hx.random_hypergraph(num_nodes = 20, num_edges_by_size = {2: 10, 4: 5}, seed = 11).get_edges(),
+        lambda: hx.configuration_model(degree_sequence = [2] * 12, edge_size = 3, seed = 11).get_edges(),
+        lambda: hx.hoad_model(num_nodes = 10, activities_per_order = {1: [0.3] * 10}, time = 10, seed = 11).get_edges(),
+        lambda: hx.sample_edges(hypergraph, k = 2, seed = 11),
+        lambda: hx.sis_contagion(hypergraph, beta = 0.5, mu = 0.2, initial_infected = [1], steps = 10, seed = 11),
+    ]
+    print([run() == run() for run in runs])
+    # assert : [True, True, True, True, True]
+
 # Eseguiamo tutte le funzioni di test
 # print_attr_meta(hypergraph=hypergraph)
 # print_get_edges(hypergraph=hypergraph)
+# print_get_edges_with_weights(hypergraph=hypergraph)
 # print_get_nodes(hypergraph=hypergraph)
 # print_get_orders(hypergraph=hypergraph)
 # print_get_sizes(hypergraph=hypergraph)
 # print_get_weight(hypergraph=hypergraph)
 # print_get_weights(hypergraph=hypergraph)
+# print_get_weights_up_to(hypergraph=hypergraph.copy())
+# print_get_weights_aligned(hypergraph=hypergraph)
 # print_is_uniform(hypergraph=hypergraph)
+# print_is_uniform_empty()
 # print_is_weighted(hypergraph=hypergraph)
 # print_max_order(hypergraph=hypergraph)
+# print_max_order_after_removal()
 # print_max_size(hypergraph=hypergraph)
 # print_num_edges(hypergraph=hypergraph)
+# print_multiplicity()
 # print_num_nodes(hypergraph=hypergraph)
 
 # print_get_meta(hypergraph=hypergraph)
 # print_get_incident_edges(hypergraph=hypergraph)
+# print_get_incident_edge_ids()
+# print_get_incident_weights(hypergraph=hypergraph)
 # print_get_neighbors(hypergraph=hypergraph)
+# print_common_neighbors(hypergraph=hypergraph)
+# print_co_occurrence(hypergraph=hypergraph)
+# print_edges_containing(hypergraph=hypergraph)
 # print_get_mapping(hypergraph=hypergraph)
+# print_filter_edges_by_meta()
+# print_nodes_with_meta()
+# print_get_all_node_metadata()
+# print_get_adjacency()
 
 # print_set_meta(hypergraph=hypergraph)
+# print_set_attr_json()
 # print_set_weight(hypergraph=hypergraph)
+# print_set_weight_vec()
+# print_weight_vec_carry_over()
+# print_normalize_weights(hypergraph=hypergraph.copy())
+# print_collapse_edges()
 
 # print_check_edge(hypergraph=hypergraph)
 # print_check_node(hypergraph=hypergraph)
 
 # print_add_edge(weighted=weighted)
+# print_add_edge_repeated_nodes()
+# print_add_empty_edge()
 # print_add_edges()
+# print_add_edges_iter()
+# print_with_capacity()
+# print_invalid_construction()
 # print_add_node()
 # print_add_nodes()
 
 # print_remove_edge(hypergraph=hypergraph)
+# print_remove_edge_keeps_nodes()
 # print_remove_edges(hypergraph=hypergraph)
+# print_restrict_to_sizes()
+# print_meta_counts_after_removal()
 # print_remove_node(hypergraph=hypergraph, keep_edges=False)
+# print_remove_node_keep_edges(hypergraph=hypergraph.copy())
+# print_remove_node_keep_edges_collision()
 # print_remove_nodes(hypergraph=hypergraph, keep_edges=False)
+# print_contract_nodes()
+# print_double_edge_swap()
+# print_prune_by_weight(hypergraph=hypergraph.copy())
+# print_isolated_nodes(hypergraph=hypergraph.copy())
 
 
 # print_distribution_sizes(hypergraph=hypergraph)
+# print_distribution_sizes_after_mutations()
+# print_distribution_orders(hypergraph=hypergraph)
 # print_subhypergraph(hypergraph= hypergraph)
+# print_subhypergraph_isolated_and_multiplicity()
+# print_subhypergraph_contained_edges(hypergraph=hypergraph)
 # print_subhypergraph_by_order(hypergraph=hypergraph)
+# print_subhypergraph_by_edges()
+# print_clique_expansion()
+# print_star_expansion()
+# print_star_expansion_multiplicity()
+# print_star(hypergraph=hypergraph)
+# print_simplicial_closure(hypergraph=hypergraph)
+# print_maximal_edges()
+# print_wl_hash()
+# print_is_isomorphic()
+# print_incidence_scipy()
+# print_from_incidence_matrix()
+# print_from_adjacency_matrix(hypergraph=hypergraph)
+# print_temporal_hypergraph()
+# print_temporal_slice()
+# print_directed_hypergraph()
+# print_forward_reachable()
+
+# print(hypergraph)
+
+# print_centralities_non_contiguous_ids()
+# print_cec_centrality_general(hypergraph=hypergraph)
+# print_cec_adjacency()
+# print_centrality_diagnostics()
+# print_top_k()
+# print_normalize_centrality(hypergraph=hypergraph)
+# print_communicability(hypergraph=hypergraph)
+# print_harmonic_centrality()
+# print_spectral_embedding(hypergraph=hypergraph)
+# print_conductance(hypergraph=hypergraph)
+# print_spectra(hypergraph=hypergraph)
+# print_degree_sequence_matches_serial(hypergraph=hypergraph)
+# print_degree_distribution_normalized(hypergraph=hypergraph)
+# print_degree_distribution_cdf(hypergraph=hypergraph)
+# print_entropies(hypergraph=hypergraph)
+# print_degree_mixing_matrix(hypergraph=hypergraph)
+# print_average_neighbor_degree(hypergraph=hypergraph)
+# print_weighted_projection_degree(hypergraph=hypergraph)
+# print_effective_size(hypergraph=hypergraph)
+# print_density()
+# print_size_stats()
+# print_edge_similarity_matrix(hypergraph=hypergraph)
+# print_overlap_coefficient()
+# print_dice_coefficient(hypergraph=hypergraph)
+# print_node_similarity(hypergraph=hypergraph)
+# print_s_connected_components(hypergraph=hypergraph)
+# print_clique_percolation()
+# print_s_centralities_options(hypergraph=hypergraph)
+# print_line_graph(hypergraph=hypergraph)
+
+# print_sis_contagion(hypergraph=hypergraph)
+# print_threshold_contagion(hypergraph=hypergraph)
+# print_stationary_distribution(hypergraph=hypergraph)
+# print_personalized_pagerank()
+# print_hitting_time()
+# print_walk_corpus(hypergraph=hypergraph)
 
-# print(hypergraph)
\ No newline at end of file
+# print_sample_edges(hypergraph=hypergraph)
+# print_bootstrap()
+# print_random_hypergraph()
+# print_configuration_model()
+# print_chung_lu()
+# print_hoad_model()
+# print_seeded_generators(hypergraph=hypergraph)